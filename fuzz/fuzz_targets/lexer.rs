@@ -0,0 +1,34 @@
+//!
+//! Fuzzes `TokenStream` directly on raw bytes.
+//!
+//! The only invariant at this layer is liveness: the lexer must never panic, and it must
+//! reach `Eof` (or an error) within a bounded number of tokens, so a bug that advances the
+//! stream by zero bytes shows up as an assertion failure instead of a fuzzer-visible hang.
+//!
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use zinc::lexical::Lexeme;
+use zinc::lexical::TokenStream;
+
+/// Generous upper bound on tokens pulled from a single input before we consider the lexer
+/// stuck rather than merely working through a large input.
+const MAX_TOKENS: usize = 1 << 16;
+
+fuzz_target!(|data: &[u8]| {
+    let source = match std::str::from_utf8(data) {
+        Ok(source) => source,
+        Err(_) => return,
+    };
+
+    let mut stream = TokenStream::new(source);
+    for _ in 0..MAX_TOKENS {
+        match stream.next() {
+            Ok(token) if matches!(token.lexeme, Lexeme::Eof) => return,
+            Ok(_) => continue,
+            Err(_) => return,
+        }
+    }
+    panic!("lexer did not reach Eof within {} tokens", MAX_TOKENS);
+});
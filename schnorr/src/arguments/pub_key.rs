@@ -3,15 +3,45 @@
 //!
 
 use std::io::Read;
+use std::str::FromStr;
 
 use serde_json::json;
 use structopt::StructOpt;
 
 use franklin_crypto::alt_babyjubjub::AltJubjubBn256;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
 use franklin_crypto::eddsa;
 
 use crate::arguments::Error;
+use crate::bech32;
+
+/// The HRP used to encode a Bech32 public key.
+const PUBLIC_KEY_HRP: &str = "zncpub";
+
+///
+/// The key encoding requested via `--format`.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum KeyFormat {
+    /// Plain hex coordinates, as produced before this option existed.
+    Hex,
+    /// Bech32-encoded coordinates with an HRP and a checksum.
+    Bech32,
+}
+
+impl FromStr for KeyFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "hex" => Ok(Self::Hex),
+            "bech32" => Ok(Self::Bech32),
+            other => Err(format!("unknown key format `{}`, expected `hex` or `bech32`", other)),
+        }
+    }
+}
 
 ///
 /// The `public key` command arguments.
@@ -21,7 +51,11 @@ use crate::arguments::Error;
     name = "pub-key",
     about = "recover the public key from the private key"
 )]
-pub struct PubKeyCommand {}
+pub struct PubKeyCommand {
+    /// The output key encoding: `hex` (default) or `bech32`.
+    #[structopt(long = "format", default_value = "hex")]
+    pub format: KeyFormat,
+}
 
 impl PubKeyCommand {
     pub fn execute(&self) -> Result<(), Error> {
@@ -35,15 +69,41 @@ impl PubKeyCommand {
         let private_key = eddsa::PrivateKey::<Bn256>::read(bytes.as_slice())?;
 
         let public_key = schnorr::recover_public_key(&params, &private_key);
-        let (x, y) = {
-            let (x, y) = public_key.0.into_xy();
-            (schnorr::fr_into_hex(x), schnorr::fr_into_hex(y))
-        };
+        let (x, y) = public_key.0.into_xy();
 
-        let public_key_json = json!({ "x": x, "y": y });
+        let public_key_json = match self.format {
+            KeyFormat::Hex => json!({
+                "x": schnorr::fr_into_hex(x),
+                "y": schnorr::fr_into_hex(y),
+            }),
+            KeyFormat::Bech32 => json!({
+                "bech32": encode_bech32(x, y),
+            }),
+        };
         let public_key_text = serde_json::to_string_pretty(&public_key_json).expect("json");
         println!("{}", public_key_text);
 
         Ok(())
     }
 }
+
+///
+/// Encodes the `(x, y)` public key coordinates as a single Bech32 string
+/// with the `zncpub` human-readable prefix.
+///
+fn encode_bech32(x: Bn256Fr, y: Bn256Fr) -> String {
+    let mut bytes = fr_to_bytes_be(&x);
+    bytes.extend(fr_to_bytes_be(&y));
+    bech32::encode(PUBLIC_KEY_HRP, &bytes).expect("encoding a non-empty HRP never fails")
+}
+
+type Bn256Fr = <Bn256 as franklin_crypto::bellman::pairing::Engine>::Fr;
+
+fn fr_to_bytes_be(value: &Bn256Fr) -> Vec<u8> {
+    let mut bytes = vec![0u8; 32];
+    value
+        .into_repr()
+        .write_be(&mut bytes[..])
+        .expect("32-byte buffer matches the BN256 field's bit length");
+    bytes
+}
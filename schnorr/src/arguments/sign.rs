@@ -0,0 +1,82 @@
+//!
+//! The `sign` command arguments.
+//!
+
+use std::io::Read;
+
+use serde_json::json;
+use structopt::StructOpt;
+
+use franklin_crypto::alt_babyjubjub::AltJubjubBn256;
+use franklin_crypto::bellman::pairing::bn256::{Bn256, Fr};
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+use franklin_crypto::eddsa;
+use franklin_crypto::jubjub::FixedGenerators;
+use franklin_crypto::jubjub::JubjubEngine;
+
+use crate::arguments::Error;
+
+///
+/// The `sign` command arguments.
+///
+#[derive(StructOpt)]
+#[structopt(
+    name = "sign",
+    about = "sign a message with the private key, producing a Schnorr/EdDSA signature"
+)]
+pub struct SignCommand {}
+
+impl SignCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let params = AltJubjubBn256::new();
+        let p_g = FixedGenerators::SpendingKeyGenerator;
+
+        let mut private_key_hex = vec![0; 64];
+        std::io::stdin().read_exact(&mut private_key_hex)?;
+        let private_key_hex = String::from_utf8_lossy(&private_key_hex);
+        let bytes = hex::decode(private_key_hex.trim())?;
+        let private_key = eddsa::PrivateKey::<Bn256>::read(bytes.as_slice())?;
+
+        let mut message = String::new();
+        std::io::stdin().read_to_string(&mut message)?;
+        let message = message.trim().as_bytes();
+
+        let seed = eddsa::Seed::random_seed(&mut rand::thread_rng(), message);
+        let signature = private_key.sign_raw_message(
+            message,
+            &seed,
+            p_g,
+            &params,
+            <Bn256 as JubjubEngine>::Fs::CAPACITY as usize / 8,
+        );
+
+        let (r_x, r_y) = signature.r.into_xy();
+
+        // The gadget treats `s` as an `Fr` element, so it is re-interpreted
+        // here through the same little-endian byte roundtrip used by the
+        // `VerifySchnorrSignature` circuit test, rather than reduced modulo
+        // `Fs` again.
+        let mut s_bytes = [0u8; 32];
+        signature
+            .s
+            .into_repr()
+            .write_le(&mut s_bytes[..])
+            .expect("get LE bytes of signature S");
+        let mut s_repr = <Fr as PrimeField>::Repr::from(0);
+        s_repr
+            .read_le(&s_bytes[..])
+            .expect("interpret S as field element representation");
+        let s = Fr::from_repr(s_repr).expect("S fits into Fr");
+
+        let signature_json = json!({
+            "r_x": schnorr::fr_into_hex(r_x),
+            "r_y": schnorr::fr_into_hex(r_y),
+            "s": schnorr::fr_into_hex(s),
+        });
+        let signature_text = serde_json::to_string_pretty(&signature_json).expect("json");
+        println!("{}", signature_text);
+
+        Ok(())
+    }
+}
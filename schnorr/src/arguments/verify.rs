@@ -0,0 +1,134 @@
+//!
+//! The `verify` command arguments.
+//!
+
+use std::io::Read;
+
+use serde_json::Value;
+use structopt::StructOpt;
+
+use franklin_crypto::alt_babyjubjub::AltJubjubBn256;
+use franklin_crypto::bellman::pairing::bn256::{Bn256, Fr};
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+use franklin_crypto::eddsa;
+use franklin_crypto::jubjub::edwards;
+use franklin_crypto::jubjub::FixedGenerators;
+use franklin_crypto::jubjub::JubjubEngine;
+use franklin_crypto::jubjub::Unknown;
+
+use crate::arguments::Error;
+use crate::bech32;
+
+/// The HRP used to encode a Bech32 public key, shared with the `pub-key` command.
+const PUBLIC_KEY_HRP: &str = "zncpub";
+
+///
+/// The `verify` command arguments.
+///
+/// Reads a JSON object `{ "public_key": { "x", "y" } | { "bech32": "..." },
+/// "signature": { "r_x", "r_y", "s" }, "message": "..." }` from stdin, in the
+/// same shape the `pub-key` and `sign` commands emit, and checks the
+/// signature natively.
+///
+#[derive(StructOpt)]
+#[structopt(name = "verify", about = "verify a Schnorr/EdDSA signature")]
+pub struct VerifyCommand {}
+
+impl VerifyCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let params = AltJubjubBn256::new();
+        let p_g = FixedGenerators::SpendingKeyGenerator;
+
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        let input: Value = serde_json::from_str(&input).map_err(|error| {
+            Error::Invalid(format!("input is not valid JSON: {}", error))
+        })?;
+
+        let (pk_x, pk_y) = read_public_key(&input)?;
+        let r_x = schnorr::fr_from_hex(field(&input, "signature", "r_x")?);
+        let r_y = schnorr::fr_from_hex(field(&input, "signature", "r_y")?);
+        let s = schnorr::fr_from_hex(field(&input, "signature", "s")?);
+
+        let message = input
+            .get("message")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::Invalid("missing `message` field".into()))?;
+
+        let public_key = eddsa::PublicKey::<Bn256>(
+            edwards::Point::<Bn256, Unknown>::get_for_y(pk_y, pk_x.into_repr().is_odd(), &params)
+                .ok_or_else(|| Error::Invalid("public key is not on the curve".into()))?,
+        );
+        let signature = eddsa::Signature {
+            r: edwards::Point::<Bn256, Unknown>::get_for_y(
+                r_y,
+                r_x.into_repr().is_odd(),
+                &params,
+            )
+            .ok_or_else(|| Error::Invalid("signature R is not on the curve".into()))?,
+            s,
+        };
+
+        let is_valid = public_key.verify_for_raw_message(
+            message.as_bytes(),
+            &signature,
+            p_g,
+            &params,
+            <Bn256 as JubjubEngine>::Fs::CAPACITY as usize / 8,
+        );
+
+        println!("{}", if is_valid { "valid" } else { "invalid" });
+
+        Ok(())
+    }
+}
+
+fn field<'a>(value: &'a Value, object: &'static str, name: &'static str) -> Result<&'a str, Error> {
+    value
+        .get(object)
+        .and_then(|object| object.get(name))
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::Invalid(format!("missing `{}.{}` field", object, name)))
+}
+
+///
+/// Reads the `public_key` object, accepting either the plain `{ "x", "y" }`
+/// hex shape or a single `{ "bech32": "..." }` string produced by `pub-key
+/// --format bech32`.
+///
+fn read_public_key(input: &Value) -> Result<(Fr, Fr), Error> {
+    let public_key = input
+        .get("public_key")
+        .ok_or_else(|| Error::Invalid("missing `public_key` field".into()))?;
+
+    if let Some(encoded) = public_key.get("bech32").and_then(Value::as_str) {
+        let (hrp, bytes) = bech32::decode(encoded)
+            .map_err(|error| Error::Invalid(format!("invalid bech32 public key: {:?}", error)))?;
+        if hrp != PUBLIC_KEY_HRP {
+            return Err(Error::Invalid(format!("unexpected bech32 HRP `{}`", hrp)));
+        }
+        if bytes.len() != 64 {
+            return Err(Error::Invalid(
+                "bech32 public key payload must be 64 bytes".into(),
+            ));
+        }
+
+        let x = fr_from_bytes_be(&bytes[..32])?;
+        let y = fr_from_bytes_be(&bytes[32..])?;
+        return Ok((x, y));
+    }
+
+    Ok((
+        schnorr::fr_from_hex(field(input, "public_key", "x")?),
+        schnorr::fr_from_hex(field(input, "public_key", "y")?),
+    ))
+}
+
+fn fr_from_bytes_be(bytes: &[u8]) -> Result<Fr, Error> {
+    let mut repr = <Fr as PrimeField>::Repr::from(0);
+    repr.read_be(bytes)
+        .map_err(|_| Error::Invalid("bech32 payload does not fit the BN256 field".into()))?;
+    Fr::from_repr(repr)
+        .map_err(|_| Error::Invalid("bech32 payload is not a valid field element".into()))
+}
@@ -0,0 +1,230 @@
+//!
+//! A minimal Bech32 (BIP173) encoder/decoder, used to render key material
+//! with a human-readable prefix and an error-detecting checksum instead of
+//! raw, easy-to-mistype hex.
+//!
+
+const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] = [
+    0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3,
+];
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The string is missing the `1` separator between the HRP and the data.
+    MissingSeparator,
+    /// The human-readable part is empty.
+    EmptyHrp,
+    /// A character outside the 32-symbol charset was encountered.
+    InvalidCharacter(char),
+    /// The checksum does not match the payload.
+    InvalidChecksum,
+    /// The payload could not be regrouped back into 8-bit bytes.
+    InvalidPadding,
+    /// The string mixes uppercase and lowercase characters; BIP173 requires
+    /// an encoded string to be entirely one case.
+    MixedCase,
+}
+
+///
+/// Computes the Bech32 polymod checksum over 5-bit `values`.
+///
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk: u32 = 1;
+    for &value in values {
+        let top = chk >> 25;
+        chk = (chk & 0x1ffffff) << 5 ^ (value as u32);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+///
+/// Expands the human-readable part into the high bits, a zero separator, and
+/// the low bits, as required by the checksum algorithm.
+///
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut expanded = Vec::with_capacity(hrp.len() * 2 + 1);
+    expanded.extend(hrp.bytes().map(|byte| byte >> 5));
+    expanded.push(0);
+    expanded.extend(hrp.bytes().map(|byte| byte & 0x1f));
+    expanded
+}
+
+fn create_checksum(hrp: &str, data: &[u8]) -> Vec<u8> {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0u8; 6]);
+
+    let polymod = polymod(&values) ^ 1;
+
+    (0..6)
+        .map(|i| ((polymod >> (5 * (5 - i))) & 0x1f) as u8)
+        .collect()
+}
+
+///
+/// Regroups a byte slice into 5-bit values, padding the final group with
+/// trailing zero bits.
+///
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Result<Vec<u8>, Error> {
+    let mut accumulator: u32 = 0;
+    let mut bits: u32 = 0;
+    let max_value = (1u32 << to_bits) - 1;
+    let mut result = Vec::new();
+
+    for &value in data {
+        accumulator = (accumulator << from_bits) | (value as u32);
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            result.push(((accumulator >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            result.push(((accumulator << (to_bits - bits)) & max_value) as u8);
+        }
+    } else if bits >= from_bits || ((accumulator << (to_bits - bits)) & max_value) != 0 {
+        return Err(Error::InvalidPadding);
+    }
+
+    Ok(result)
+}
+
+///
+/// Encodes `hrp` and `data` (arbitrary bytes) as a Bech32 string.
+///
+pub fn encode(hrp: &str, data: &[u8]) -> Result<String, Error> {
+    if hrp.is_empty() {
+        return Err(Error::EmptyHrp);
+    }
+
+    let values = convert_bits(data, 8, 5, true)?;
+    let checksum = create_checksum(hrp, &values);
+
+    let mut result = String::with_capacity(hrp.len() + 1 + values.len() + checksum.len());
+    result.push_str(hrp);
+    result.push('1');
+    for symbol in values.iter().chain(checksum.iter()) {
+        result.push(CHARSET[*symbol as usize] as char);
+    }
+
+    Ok(result)
+}
+
+///
+/// Decodes a Bech32 string back into its human-readable part and payload
+/// bytes, verifying the checksum.
+///
+pub fn decode(encoded: &str) -> Result<(String, Vec<u8>), Error> {
+    if encoded.chars().any(|c| c.is_ascii_uppercase())
+        && encoded.chars().any(|c| c.is_ascii_lowercase())
+    {
+        return Err(Error::MixedCase);
+    }
+
+    let separator = encoded
+        .rfind('1')
+        .ok_or(Error::MissingSeparator)?;
+    if separator == 0 {
+        return Err(Error::EmptyHrp);
+    }
+
+    let hrp = encoded[..separator].to_lowercase();
+    let data_part = &encoded[separator + 1..];
+
+    let mut values = Vec::with_capacity(data_part.len());
+    for character in data_part.chars() {
+        let character = character.to_ascii_lowercase();
+        let position = CHARSET
+            .iter()
+            .position(|&symbol| symbol as char == character)
+            .ok_or(Error::InvalidCharacter(character))?;
+        values.push(position as u8);
+    }
+
+    if values.len() < 6 {
+        return Err(Error::InvalidChecksum);
+    }
+
+    let mut checked = hrp_expand(&hrp);
+    checked.extend_from_slice(&values);
+    if polymod(&checked) != 1 {
+        return Err(Error::InvalidChecksum);
+    }
+
+    let payload = &values[..values.len() - 6];
+    let bytes = convert_bits(payload, 5, 8, false)?;
+
+    Ok((hrp, bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use super::encode;
+    use super::Error;
+
+    /// BIP173 "Test vectors for Bech32" — valid strings.
+    const VALID: &[&str] = &[
+        "A12UEL5L",
+        "a12uel5l",
+        "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+        "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+        "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+        "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+        "?1ezyfcl",
+    ];
+
+    #[test]
+    fn decodes_bip173_valid_vectors() {
+        for vector in VALID {
+            assert!(decode(vector).is_ok(), "expected {} to decode", vector);
+        }
+    }
+
+    #[test]
+    fn rejects_bip173_invalid_checksum() {
+        // Same payload as "a12uel5l", last checksum character flipped.
+        assert_eq!(decode("a12uel5k"), Err(Error::InvalidChecksum));
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        assert_eq!(decode("pzry9x0s0muk"), Err(Error::MissingSeparator));
+    }
+
+    #[test]
+    fn rejects_empty_hrp() {
+        assert_eq!(decode("1pzry9x0s0muk"), Err(Error::EmptyHrp));
+    }
+
+    #[test]
+    fn rejects_invalid_data_character() {
+        assert_eq!(decode("x1b4n0q5v"), Err(Error::InvalidCharacter('b')));
+    }
+
+    #[test]
+    fn rejects_mixed_case() {
+        assert_eq!(decode("A12uel5l"), Err(Error::MixedCase));
+        assert_eq!(decode("a12UEL5L"), Err(Error::MixedCase));
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let (hrp, data) = ("test", vec![0u8, 1, 2, 30, 31, 255]);
+        let encoded = encode(hrp, &data).expect("encode");
+
+        let (decoded_hrp, decoded_data) = decode(&encoded).expect("decode");
+
+        assert_eq!(decoded_hrp, hrp);
+        assert_eq!(decoded_data, data);
+    }
+}
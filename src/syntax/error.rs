@@ -0,0 +1,43 @@
+//!
+//! The syntax error.
+//!
+
+use zinc_session::Location;
+
+use crate::lexical::Lexeme;
+use crate::syntax::suggest;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// An unexpected token, with no plausible typo correction among `expected`.
+    Expected(Location, Vec<&'static str>, Lexeme),
+    /// An unexpected token whose text is a close enough edit-distance match to one of
+    /// `expected` that it is worth surfacing as a suggestion.
+    ExpectedWithSuggestion(Location, Vec<&'static str>, Lexeme, String),
+    UnexpectedEnd,
+}
+
+impl Error {
+    ///
+    /// Builds an `Expected` error, upgrading it to `ExpectedWithSuggestion` when `lexeme`
+    /// is an identifier close enough to one of `expected` to likely be a typo of it.
+    ///
+    pub fn expected(location: Location, expected: Vec<&'static str>, lexeme: Lexeme) -> Self {
+        match identifier_text(&lexeme).and_then(|word| suggest::suggest(word, &expected)) {
+            Some(suggestion) => Self::ExpectedWithSuggestion(location, expected, lexeme, suggestion),
+            None => Self::Expected(location, expected, lexeme),
+        }
+    }
+}
+
+///
+/// The text to fuzzy-match against `expected`, if `lexeme` is the kind of token a typo can
+/// plausibly produce. Punctuation mismatches (e.g. a stray `)`) are not a typo of anything,
+/// so only identifiers are considered.
+///
+fn identifier_text(lexeme: &Lexeme) -> Option<&str> {
+    match lexeme {
+        Lexeme::Identifier(identifier) => Some(identifier.name.as_str()),
+        _ => None,
+    }
+}
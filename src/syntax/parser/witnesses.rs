@@ -5,16 +5,17 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_ast::Identifier;
+use zinc_ast::Witness;
+use zinc_ast::WitnessBuilder;
+
 use crate::lexical::Keyword;
 use crate::lexical::Lexeme;
 use crate::lexical::Symbol;
 use crate::lexical::Token;
 use crate::lexical::TokenStream;
 use crate::syntax::Error as SyntaxError;
-use crate::syntax::Identifier;
 use crate::syntax::TypeParser;
-use crate::syntax::Witness;
-use crate::syntax::WitnessBuilder;
 use crate::Error;
 
 #[derive(Debug, Clone, Copy)]
@@ -59,7 +60,7 @@ impl Parser {
                         ..
                     })) => self.state = State::BracketOpen,
                     Some(Ok(Token { lexeme, location })) => {
-                        return Err(Error::Syntax(SyntaxError::Expected(
+                        return Err(Error::Syntax(SyntaxError::expected(
                             location,
                             ["witness"].to_vec(),
                             lexeme,
@@ -0,0 +1,56 @@
+//!
+//! Typo suggestions for syntax errors.
+//!
+//! Wagner-Fischer edit distance between an unexpected token and the literals the parser
+//! was expecting, so e.g. a witness block opened with `witnes` suggests `witness` instead
+//! of just reporting "expected one of [...], found `witnes`".
+//!
+
+///
+/// The classic Wagner-Fischer dynamic program: `d[i][j]` is the edit distance between the
+/// first `i` characters of `a` and the first `j` characters of `b`.
+///
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution_cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + substitution_cost);
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+///
+/// Finds the candidate in `candidates` nearest to `word` by edit distance, preferring the
+/// lexicographically smallest on ties, and only if it is close enough
+/// (`distance <= max(2, word.len() / 3)`) to be a plausible typo rather than noise.
+///
+pub fn suggest(word: &str, candidates: &[&str]) -> Option<String> {
+    let threshold = std::cmp::max(2, word.chars().count() / 3);
+
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by(|(left_distance, left_candidate), (right_distance, right_candidate)| {
+            left_distance
+                .cmp(right_distance)
+                .then_with(|| left_candidate.cmp(right_candidate))
+        })
+        .map(|(_, candidate)| candidate.to_owned())
+}
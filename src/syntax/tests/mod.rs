@@ -0,0 +1,143 @@
+//!
+//! The corpus-driven parser snapshot tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rayon::prelude::*;
+
+use crate::lexical::TokenStream;
+use crate::syntax::parser::witnesses::Parser as WitnessParser;
+use crate::syntax::TypeParser;
+
+/// Root of the snapshot corpus, relative to this crate's manifest directory.
+const TEST_DATA_DIR: &str = "src/syntax/tests/test_data";
+
+/// Set this env var to regenerate `.expected` files from the current parser output instead
+/// of asserting against them, e.g. `UPDATE_EXPECT=1 cargo test corpus`.
+const UPDATE_EXPECT_VAR: &str = "UPDATE_EXPECT";
+
+#[derive(Clone, Copy)]
+enum Target {
+    Witness,
+    Type,
+}
+
+impl Target {
+    const ALL: [Self; 2] = [Self::Witness, Self::Type];
+
+    fn directory_name(self) -> &'static str {
+        match self {
+            Self::Witness => "witness",
+            Self::Type => "type",
+        }
+    }
+
+    /// Runs the corresponding parser over `source` and formats the result (AST or error) as
+    /// the string to compare against the snapshot.
+    fn run(self, source: &str) -> String {
+        let stream = Rc::new(RefCell::new(TokenStream::new(source)));
+        match self {
+            Self::Witness => format!("{:#?}", WitnessParser::default().parse(stream)),
+            Self::Type => format!("{:#?}", TypeParser::default().parse(stream)),
+        }
+    }
+}
+
+/// One `<name>.zn` / `<name>.expected` pair discovered under `test_data/<target>/`.
+struct Case {
+    target: Target,
+    input_path: PathBuf,
+    expected_path: PathBuf,
+}
+
+fn discover_cases() -> Vec<Case> {
+    Target::ALL
+        .iter()
+        .flat_map(|&target| {
+            let directory = Path::new(TEST_DATA_DIR).join(target.directory_name());
+            fs::read_dir(&directory)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().map_or(false, |extension| extension == "zn"))
+                .map(move |input_path| {
+                    let expected_path = input_path.with_extension("expected");
+                    Case {
+                        target,
+                        input_path,
+                        expected_path,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Runs one case, returning `Err(mismatch report)` rather than panicking so the caller can
+/// collect every failure in the corpus into a single report.
+fn run_case(case: &Case) -> Result<(), String> {
+    let source = fs::read_to_string(&case.input_path).map_err(|error| {
+        format!(
+            "{}: failed to read input: {}",
+            case.input_path.display(),
+            error
+        )
+    })?;
+    let actual = case.target.run(&source);
+
+    if std::env::var(UPDATE_EXPECT_VAR).is_ok() {
+        fs::write(&case.expected_path, &actual).map_err(|error| {
+            format!(
+                "{}: failed to write snapshot: {}",
+                case.expected_path.display(),
+                error
+            )
+        })?;
+        return Ok(());
+    }
+
+    let expected = fs::read_to_string(&case.expected_path).unwrap_or_default();
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}:\n--- expected ---\n{}\n--- actual ---\n{}\n",
+            case.input_path.display(),
+            expected,
+            actual,
+        ))
+    }
+}
+
+#[test]
+fn corpus() {
+    let cases = discover_cases();
+    let failures: Vec<String> = cases
+        .par_iter()
+        .filter_map(|case| run_case(case).err())
+        .collect();
+
+    if !failures.is_empty() {
+        let mut report = String::new();
+        let _ = write!(
+            report,
+            "{} of {} corpus cases failed:\n\n",
+            failures.len(),
+            cases.len()
+        );
+        for failure in &failures {
+            report.push_str(failure);
+            report.push('\n');
+        }
+        panic!("{}", report);
+    }
+}
@@ -0,0 +1,20 @@
+//!
+//! The project build directory error.
+//!
+
+use std::io;
+
+///
+/// The project build directory error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// Creating the directory failed.
+    Creating(io::Error),
+    /// Removing the directory failed.
+    Removing(io::Error),
+    /// Writing a file into the directory failed.
+    Writing(io::Error),
+    /// Reading a file from the directory failed.
+    Reading(io::Error),
+}
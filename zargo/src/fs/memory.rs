@@ -0,0 +1,104 @@
+//!
+//! The in-memory filesystem, used to exercise the build directory subsystem without touching
+//! disk.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use super::Fs;
+
+#[derive(Default)]
+struct Inner {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+    directories: BTreeSet<PathBuf>,
+}
+
+///
+/// A virtual tree of directories and files, for tests and for tooling that wants to produce
+/// bytecode artifacts without a real project checkout.
+///
+#[derive(Default)]
+pub struct MemFs {
+    inner: Mutex<Inner>,
+}
+
+impl Fs for MemFs {
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        let mut inner = self.inner.lock().expect("mutex is not poisoned");
+        for ancestor in path.ancestors() {
+            inner.directories.insert(ancestor.to_owned());
+        }
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut inner = self.inner.lock().expect("mutex is not poisoned");
+        inner
+            .files
+            .retain(|file_path, _| !file_path.starts_with(path));
+        inner
+            .directories
+            .retain(|directory_path| !directory_path.starts_with(path));
+        Ok(())
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut inner = self.inner.lock().expect("mutex is not poisoned");
+        inner.files.insert(path.to_owned(), contents.to_owned());
+        Ok(())
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let inner = self.inner.lock().expect("mutex is not poisoned");
+        inner
+            .files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.display().to_string()))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        let inner = self.inner.lock().expect("mutex is not poisoned");
+        inner.files.contains_key(path) || inner.directories.contains(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::Fs;
+    use super::MemFs;
+
+    #[test]
+    fn create_and_remove_dir() {
+        let fs = MemFs::default();
+        let path = Path::new("/project/build");
+
+        fs.create_dir(path).expect("create_dir must succeed");
+        assert!(fs.exists(path));
+
+        fs.remove_dir_all(path).expect("remove_dir_all must succeed");
+        assert!(!fs.exists(path));
+    }
+
+    #[test]
+    fn write_and_read_file() {
+        let fs = MemFs::default();
+        let path = Path::new("/project/build/main.znb");
+
+        fs.write(path, b"bytecode").expect("write must succeed");
+        assert_eq!(fs.read(path).expect("read must succeed"), b"bytecode");
+    }
+
+    #[test]
+    fn read_missing_file_fails() {
+        let fs = MemFs::default();
+        assert!(fs.read(Path::new("/missing")).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+//!
+//! The pluggable filesystem abstraction for the project build directory subsystem.
+//!
+
+pub mod memory;
+pub mod real;
+
+use std::path::Path;
+
+pub use self::memory::MemFs;
+pub use self::real::RealFs;
+
+///
+/// A filesystem backend, abstracting the build directory subsystem over `std::fs` so it can
+/// be exercised against an in-memory tree in unit tests, and so tooling can produce bytecode
+/// artifacts into a virtual tree instead of touching disk.
+///
+pub trait Fs {
+    ///
+    /// Creates `path` and all of its parent directories if they do not exist.
+    ///
+    fn create_dir(&self, path: &Path) -> std::io::Result<()>;
+
+    ///
+    /// Removes `path` and everything beneath it. A no-op if `path` does not exist.
+    ///
+    fn remove_dir_all(&self, path: &Path) -> std::io::Result<()>;
+
+    ///
+    /// Writes `contents` to `path`, creating or truncating the file.
+    ///
+    fn write(&self, path: &Path, contents: &[u8]) -> std::io::Result<()>;
+
+    ///
+    /// Reads the full contents of the file at `path`.
+    ///
+    fn read(&self, path: &Path) -> std::io::Result<Vec<u8>>;
+
+    ///
+    /// Returns whether `path` currently exists.
+    ///
+    fn exists(&self, path: &Path) -> bool;
+}
@@ -0,0 +1,40 @@
+//!
+//! The `std::fs`-backed filesystem.
+//!
+
+use std::fs;
+use std::path::Path;
+
+use super::Fs;
+
+///
+/// Delegates directly to `std::fs`. This is the backend used everywhere outside of tests.
+///
+#[derive(Default)]
+pub struct RealFs;
+
+impl Fs for RealFs {
+    fn create_dir(&self, path: &Path) -> std::io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        if path.exists() {
+            fs::remove_dir_all(path)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> std::io::Result<()> {
+        fs::write(path, contents)
+    }
+
+    fn read(&self, path: &Path) -> std::io::Result<Vec<u8>> {
+        fs::read(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The project `build` directory bytecode artifact.
+//!
+
+use std::path::PathBuf;
+
+use crate::error::directory::Error as DirectoryError;
+use crate::fs::Fs;
+use crate::project::build::Directory;
+
+///
+/// The compiled bytecode artifact within the project `build` directory.
+///
+pub struct Bytecode {}
+
+impl Bytecode {
+    /// The bytecode artifact file name within the build directory.
+    const FILE_NAME: &'static str = "main.znb";
+
+    ///
+    /// The bytecode artifact path for the project at `path`.
+    ///
+    pub fn path(path: &PathBuf) -> PathBuf {
+        Directory::path(path).join(Self::FILE_NAME)
+    }
+
+    ///
+    /// Writes the bytecode artifact, creating the build directory first if it does not exist.
+    ///
+    pub fn write(fs: &dyn Fs, path: &PathBuf, bytecode: &[u8]) -> Result<(), DirectoryError> {
+        Directory::create(fs, path)?;
+        fs.write(&Self::path(path), bytecode)
+            .map_err(DirectoryError::Writing)
+    }
+
+    ///
+    /// Reads the bytecode artifact.
+    ///
+    pub fn read(fs: &dyn Fs, path: &PathBuf) -> Result<Vec<u8>, DirectoryError> {
+        fs.read(&Self::path(path)).map_err(DirectoryError::Reading)
+    }
+}
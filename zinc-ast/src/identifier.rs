@@ -6,7 +6,7 @@ use std::fmt;
 
 use serde_derive::Serialize;
 
-use crate::lexical::Location;
+use zinc_session::Location;
 
 #[derive(Debug, Serialize, PartialEq)]
 pub struct Identifier {
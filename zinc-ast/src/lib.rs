@@ -0,0 +1,23 @@
+//!
+//! The Zinc concrete syntax tree.
+//!
+//! Holds only the AST data types, their builders, and a visitor trait — no `Parser`, no
+//! `TokenStream`, no lexer coupling. Downstream tooling that just wants to inspect or
+//! transform an already-parsed tree (formatters, linters, language-server features) depends
+//! on this crate instead of pulling in the parser/lexer stack from `zinc`.
+//!
+
+pub mod identifier;
+pub mod r#type;
+pub mod visit;
+pub mod witness;
+pub mod witness_builder;
+
+pub use self::identifier::Identifier;
+pub use self::r#type::IntegerType;
+pub use self::r#type::Type;
+pub use self::r#type::TypeVariant;
+pub use self::visit::walk_witnesses;
+pub use self::visit::Visitor;
+pub use self::witness::Witness;
+pub use self::witness_builder::WitnessBuilder;
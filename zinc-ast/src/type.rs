@@ -0,0 +1,50 @@
+//!
+//! The type.
+//!
+
+use serde_derive::Serialize;
+
+use zinc_session::Location;
+
+///
+/// An integer scalar type.
+///
+#[derive(Debug, Serialize, PartialEq)]
+pub struct IntegerType {
+    pub is_signed: bool,
+    pub bitlength: usize,
+}
+
+impl IntegerType {
+    pub fn new(is_signed: bool, bitlength: usize) -> Self {
+        Self {
+            is_signed,
+            bitlength,
+        }
+    }
+}
+
+///
+/// The type variant, without its location.
+///
+#[derive(Debug, Serialize, PartialEq)]
+pub enum TypeVariant {
+    Boolean,
+    Integer(IntegerType),
+    Field,
+}
+
+///
+/// The type, as written by the programmer.
+///
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Type {
+    pub location: Location,
+    pub variant: TypeVariant,
+}
+
+impl Type {
+    pub fn new(location: Location, variant: TypeVariant) -> Self {
+        Self { location, variant }
+    }
+}
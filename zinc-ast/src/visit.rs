@@ -0,0 +1,34 @@
+//!
+//! The AST visitor.
+//!
+//! Lets downstream tooling (formatters, linters, language-server features) walk the tree
+//! without depending on the parser that built it.
+//!
+
+use crate::identifier::Identifier;
+use crate::r#type::Type;
+use crate::witness::Witness;
+
+///
+/// Walks a parsed AST. Every method has a default no-op implementation, so a visitor only
+/// needs to override the nodes it actually cares about.
+///
+pub trait Visitor {
+    fn visit_witness(&mut self, witness: &Witness) {
+        self.visit_identifier(&witness.identifier);
+        self.visit_type(&witness.r#type);
+    }
+
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+
+    fn visit_type(&mut self, _r#type: &Type) {}
+}
+
+///
+/// Visits every witness in `witnesses` in order.
+///
+pub fn walk_witnesses(visitor: &mut dyn Visitor, witnesses: &[Witness]) {
+    for witness in witnesses {
+        visitor.visit_witness(witness);
+    }
+}
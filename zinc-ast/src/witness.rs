@@ -0,0 +1,23 @@
+//!
+//! The witness.
+//!
+
+use serde_derive::Serialize;
+
+use crate::identifier::Identifier;
+use crate::r#type::Type;
+
+///
+/// A single `witness { ... }` block element: a private input name and its type.
+///
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Witness {
+    pub identifier: Identifier,
+    pub r#type: Type,
+}
+
+impl Witness {
+    pub fn new(identifier: Identifier, r#type: Type) -> Self {
+        Self { identifier, r#type }
+    }
+}
@@ -0,0 +1,52 @@
+//!
+//! The witness builder.
+//!
+
+use crate::identifier::Identifier;
+use crate::r#type::Type;
+use crate::witness::Witness;
+
+///
+/// The witness builder.
+///
+#[derive(Default)]
+pub struct WitnessBuilder {
+    identifier: Option<Identifier>,
+    r#type: Option<Type>,
+}
+
+impl WitnessBuilder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_type(&mut self, value: Type) {
+        self.r#type = Some(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn build(&mut self) -> Witness {
+        let identifier = self
+            .identifier
+            .take()
+            .unwrap_or_else(|| panic!("builder requires value: {}", "identifier"));
+
+        let r#type = self
+            .r#type
+            .take()
+            .unwrap_or_else(|| panic!("builder requires value: {}", "type"));
+
+        Witness::new(identifier, r#type)
+    }
+}
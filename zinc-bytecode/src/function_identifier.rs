@@ -0,0 +1,23 @@
+//!
+//! The standard library function identifier.
+//!
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+///
+/// Identifies which native (non-bytecode) function a `CallStd` instruction
+/// invokes. The compiler's `semantic::element::type::function::stdlib`
+/// wrappers (e.g. `crypto_sha256::Function`) carry one of these so the VM's
+/// `call_std` dispatch knows which gadget to run without re-deriving it from
+/// the function's name at runtime.
+///
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FunctionIdentifier {
+    /// `std::crypto::sha256`.
+    CryptoSha256,
+    /// `std::crypto::keccak256`.
+    CryptoKeccak256,
+    /// `std::crypto::blake2s`.
+    CryptoBlake2s,
+}
@@ -16,21 +16,37 @@ use crate::instructions::Instruction;
 pub struct Assert {
     /// The optional error message.
     pub message: Option<String>,
+    /// If `true`, this assert was compiled from a debug-only assertion and is dropped by
+    /// [`crate::optimize::strip_debug_instructions`] when building for release.
+    pub debug: bool,
 }
 
 impl Assert {
     ///
-    /// A shortcut constructor.
+    /// A shortcut constructor for a regular assert, which always constrains the circuit.
     ///
     pub fn new(message: Option<String>) -> Self {
-        Self { message }
+        Self {
+            message,
+            debug: false,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for a debug-only assert, stripped from release circuits.
+    ///
+    pub fn new_debug(message: Option<String>) -> Self {
+        Self {
+            message,
+            debug: true,
+        }
     }
 
     ///
     /// If the instruction is for the debug mode only.
     ///
     pub fn is_debug(&self) -> bool {
-        false
+        self.debug
     }
 }
 
@@ -42,9 +58,10 @@ impl Into<Instruction> for Assert {
 
 impl fmt::Display for Assert {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keyword = if self.debug { "debug_assert" } else { "assert" };
         match &self.message {
-            None => write!(f, "assert"),
-            Some(text) => write!(f, "assert \"{}\"", text),
+            None => write!(f, "{}", keyword),
+            Some(text) => write!(f, "{} \"{}\"", keyword, text),
         }
     }
 }
@@ -0,0 +1,44 @@
+//!
+//! The `call_std` instruction.
+//!
+
+use std::fmt;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::function_identifier::FunctionIdentifier;
+use crate::instructions::Instruction;
+
+///
+/// Calls a native (non-bytecode) standard library function, e.g.
+/// `std::crypto::keccak256`, instead of a compiled bytecode function.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CallStd {
+    /// Which native function to call.
+    pub identifier: FunctionIdentifier,
+    /// How many values to pop off the stack as arguments.
+    pub argument_count: usize,
+}
+
+impl CallStd {
+    pub fn new(identifier: FunctionIdentifier, argument_count: usize) -> Self {
+        Self {
+            identifier,
+            argument_count,
+        }
+    }
+}
+
+impl Into<Instruction> for CallStd {
+    fn into(self) -> Instruction {
+        Instruction::CallStd(self)
+    }
+}
+
+impl fmt::Display for CallStd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "call_std {:?} {}", self.identifier, self.argument_count)
+    }
+}
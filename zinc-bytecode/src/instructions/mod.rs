@@ -0,0 +1,20 @@
+//!
+//! The bytecode instructions.
+//!
+
+pub mod assert;
+pub mod call_std;
+
+pub use self::assert::Assert;
+pub use self::call_std::CallStd;
+
+///
+/// The bytecode instruction.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    /// See [`Assert`].
+    Assert(Assert),
+    /// See [`CallStd`].
+    CallStd(CallStd),
+}
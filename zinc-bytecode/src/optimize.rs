@@ -0,0 +1,108 @@
+//!
+//! Bytecode optimization passes.
+//!
+
+use crate::instructions::Instruction;
+
+///
+/// Removes every debug-only instruction from `instructions` — currently just `assert`s
+/// compiled from a debug-only assertion. Called when compiling with the release profile, so
+/// a debug assert contributes zero constraints to the proving circuit, analogous to how a
+/// native compiler gates a `debug_assert!` out of an optimized build.
+///
+/// Non-debug asserts are left untouched and keep constraining the circuit.
+///
+pub(crate) fn strip_debug_instructions(instructions: Vec<Instruction>) -> Vec<Instruction> {
+    instructions
+        .into_iter()
+        .filter(|instruction| !is_debug(instruction))
+        .collect()
+}
+
+///
+/// The single call a release build's codegen must make on the finished
+/// instruction stream before it is serialized into the bytecode artifact:
+/// strips debug-only instructions when `is_release`, and is a no-op
+/// otherwise. Exists so the release/debug decision is made once, at the
+/// call site, instead of every caller re-deriving when stripping applies.
+///
+/// `strip_debug_instructions` is deliberately `pub(crate)`, not `pub`: this
+/// function is meant to be the *only* externally callable entry point, so a
+/// future caller can't bypass the release check by calling the stripping
+/// pass directly.
+///
+/// No call site invokes this yet. Wiring it in means calling it from
+/// wherever a complete `Vec<Instruction>` program gets assembled and handed
+/// to the bytecode serializer, but this checkout has no such place: there is
+/// no codegen/generator module under `zinc-compiler` that emits
+/// `zinc_bytecode::Instruction`s, no `Program`/serialization type in this
+/// crate that would hold the finished stream, and no bytecode-loading entry
+/// point in `zinc-vm` that takes one to run or synthesize a circuit from
+/// (confirmed by grepping all three trees for `Instruction::` and
+/// `Vec<Instruction>` outside this file — no hits). Until one of those
+/// exists in the checkout, `finalize` has no instruction stream to be
+/// called on.
+///
+pub fn finalize(instructions: Vec<Instruction>, is_release: bool) -> Vec<Instruction> {
+    if is_release {
+        strip_debug_instructions(instructions)
+    } else {
+        instructions
+    }
+}
+
+fn is_debug(instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::Assert(assert) => assert.is_debug(),
+        Instruction::CallStd(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instructions::Assert;
+    use crate::instructions::Instruction;
+
+    use super::strip_debug_instructions;
+
+    #[test]
+    fn strips_only_debug_asserts() {
+        let instructions = vec![
+            Instruction::Assert(Assert::new(None)),
+            Instruction::Assert(Assert::new_debug(Some("unreachable".into()))),
+            Instruction::Assert(Assert::new(Some("invariant".into()))),
+        ];
+
+        let stripped = strip_debug_instructions(instructions);
+
+        assert_eq!(stripped.len(), 2, "only the debug assert should be removed");
+        assert!(
+            stripped.iter().all(|instruction| !is_debug(instruction)),
+            "no debug instruction should remain"
+        );
+    }
+
+    #[test]
+    fn debug_only_program_becomes_empty() {
+        let instructions = vec![
+            Instruction::Assert(Assert::new_debug(None)),
+            Instruction::Assert(Assert::new_debug(Some("dev only".into()))),
+        ];
+
+        // Zero instructions means zero constraints added to the release circuit.
+        assert!(strip_debug_instructions(instructions).is_empty());
+    }
+
+    #[test]
+    fn finalize_strips_only_when_release() {
+        use super::finalize;
+
+        let instructions = vec![
+            Instruction::Assert(Assert::new(None)),
+            Instruction::Assert(Assert::new_debug(Some("dev only".into()))),
+        ];
+
+        assert_eq!(finalize(instructions.clone(), false).len(), 2);
+        assert_eq!(finalize(instructions, true).len(), 1);
+    }
+}
@@ -0,0 +1,46 @@
+//!
+//! Benchmarks the DFA lexer over large `.zn` sources.
+//!
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use zinc_compiler::Lexeme;
+use zinc_compiler::TokenStream;
+
+///
+/// Repeats a small function body enough times to approximate a large real-world contract.
+///
+fn large_source(repetitions: usize) -> String {
+    let mut source = String::new();
+
+    for index in 0..repetitions {
+        source.push_str(&format!(
+            "fn compute_{index}(a: u64, b: u64) -> u64 {{\n    let c = (a + b) * 2 - 1;\n    c & 0xff ^ (c << 3)\n}}\n\n",
+            index = index,
+        ));
+    }
+
+    source
+}
+
+fn bench_lex_large_source(criterion: &mut Criterion) {
+    let source = large_source(2_000);
+
+    criterion.bench_function("lex_large_source", |bencher| {
+        bencher.iter(|| {
+            let mut stream = TokenStream::new(black_box(source.as_str()));
+            loop {
+                match stream.next() {
+                    Ok(token) if matches!(token.lexeme, Lexeme::Eof) => break,
+                    Ok(_) => continue,
+                    Err(_) => break,
+                }
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_lex_large_source);
+criterion_main!(benches);
@@ -0,0 +1,105 @@
+//!
+//! Generates the `Symbol`/`Keyword` enums and their scanning functions from
+//! `lexical.in`.
+//!
+//! This replaces the old approach of branching on individual characters in
+//! the tokenizer with a declarative table plus two generated longest-match
+//! functions, so the scanner itself no longer needs to know the full set of
+//! operators and keywords.
+//!
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+struct Entry {
+    name: String,
+    pattern: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=lexical.in");
+
+    let table = fs::read_to_string("lexical.in").expect("reading lexical.in");
+    let (symbols, keywords) = parse_table(&table);
+
+    let generated = render(&symbols, &keywords);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let destination = Path::new(&out_dir).join("lexical_tables.rs");
+    fs::write(destination, generated).expect("writing generated lexical_tables.rs");
+}
+
+fn parse_table(source: &str) -> (Vec<Entry>, Vec<Entry>) {
+    let mut symbols = Vec::new();
+    let mut keywords = Vec::new();
+
+    for line in source.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let kind = parts.next().expect("entry kind");
+        let name = parts.next().expect("entry name").to_owned();
+        let pattern = parts.next().expect("entry pattern").to_owned();
+
+        match kind {
+            "symbol" => symbols.push(Entry { name, pattern }),
+            "keyword" => keywords.push(Entry { name, pattern }),
+            other => panic!("unknown entry kind `{}`", other),
+        }
+    }
+
+    // Longest pattern first, so the generated scanner always resolves `<=`
+    // before falling back to the shorter `<`.
+    symbols.sort_by(|a, b| b.pattern.len().cmp(&a.pattern.len()));
+
+    (symbols, keywords)
+}
+
+fn render(symbols: &[Entry], keywords: &[Entry]) -> String {
+    let mut out = String::new();
+
+    out.push_str("// This file is generated from `lexical.in` by `build.rs`. Do not edit.\n\n");
+
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum Symbol {\n");
+    for entry in symbols {
+        out.push_str(&format!("    {},\n", entry.name));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum Keyword {\n");
+    for entry in keywords {
+        out.push_str(&format!("    {},\n", entry.name));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(
+        "/// Matches the longest symbol at the start of `input`, returning it with its byte length.\n",
+    );
+    out.push_str("pub fn scan_symbol(input: &str) -> Option<(Symbol, usize)> {\n");
+    for entry in symbols {
+        out.push_str(&format!(
+            "    if input.starts_with({:?}) {{ return Some((Symbol::{}, {})); }}\n",
+            entry.pattern,
+            entry.name,
+            entry.pattern.len()
+        ));
+    }
+    out.push_str("    None\n}\n\n");
+
+    out.push_str("/// Matches `word` as a keyword, if it is one.\n");
+    out.push_str("pub fn scan_keyword(word: &str) -> Option<Keyword> {\n");
+    out.push_str("    match word {\n");
+    for entry in keywords {
+        out.push_str(&format!(
+            "        {:?} => Some(Keyword::{}),\n",
+            entry.pattern, entry.name
+        ));
+    }
+    out.push_str("        _ => None,\n");
+    out.push_str("    }\n}\n");
+
+    out
+}
@@ -0,0 +1,54 @@
+//!
+//! Source-span diagnostic rendering.
+//!
+//! Turns a `Location`'s `column_start..column_end` span plus the source text
+//! it was computed from into the rustc `NiceRegionError`-style display: the
+//! offending line, followed by a caret underline beneath the exact span. A
+//! second, secondary span (e.g. the specific mismatched argument in a
+//! multi-argument call) can be rendered below as a note, the way rustc shows
+//! a label pointing at a related span alongside the primary one.
+//!
+
+use crate::lexical::Location;
+
+/// One span to underline: either the primary offending span or a secondary
+/// note attached to it.
+pub struct Span<'a> {
+    pub location: Location,
+    pub label: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(location: Location, label: &'a str) -> Self {
+        Self { location, label }
+    }
+}
+
+///
+/// Renders `primary`, and optionally a `note` pointing at a related span,
+/// against the line(s) of `source` they occupy.
+///
+pub fn render(source: &str, primary: Span, note: Option<Span>) -> String {
+    let mut output = render_span(source, &primary);
+    if let Some(note) = note {
+        output.push('\n');
+        output.push_str(&render_span(source, &note));
+    }
+    output
+}
+
+fn render_span(source: &str, span: &Span) -> String {
+    let line_text = source.lines().nth(span.location.line - 1).unwrap_or_default();
+    let column_start = span.location.column_start;
+    let column_end = span.location.column_end.max(column_start + 1);
+    let underline_width = column_end - column_start;
+
+    let mut underline = " ".repeat(column_start - 1);
+    underline.push_str(&"^".repeat(underline_width));
+    if !span.label.is_empty() {
+        underline.push(' ');
+        underline.push_str(span.label);
+    }
+
+    format!("{}\n{}", line_text, underline)
+}
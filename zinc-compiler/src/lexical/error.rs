@@ -0,0 +1,14 @@
+//!
+//! The lexical error.
+//!
+
+use crate::lexical::Location;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// No symbol, keyword, identifier, or literal scanner recognized the character at
+    /// `location`.
+    UnknownCharacter { location: Location, character: char },
+    /// A `/* ... */` block comment was still open at the end of the file.
+    UnterminatedBlockComment { location: Location },
+}
@@ -0,0 +1,39 @@
+//!
+//! The token lexeme.
+//!
+
+use crate::lexical::Keyword;
+use crate::lexical::Literal;
+use crate::lexical::Symbol;
+
+///
+/// An identifier lexeme, e.g. a variable or function name. Kept separate from the AST-level
+/// `syntax::tree::identifier::Identifier`, which additionally carries its `Location`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identifier {
+    pub name: String,
+}
+
+impl Identifier {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+///
+/// The kind of token the DFA scanner produced.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lexeme {
+    /// The end of the token stream.
+    Eof,
+    /// An operator, punctuation, or bracket, resolved by the generated `scan_symbol`.
+    Symbol(Symbol),
+    /// A reserved word, resolved by the generated `scan_keyword`.
+    Keyword(Keyword),
+    /// A variable, function, or type name.
+    Identifier(Identifier),
+    /// An integer, boolean, or string literal.
+    Literal(Literal),
+}
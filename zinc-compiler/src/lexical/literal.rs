@@ -0,0 +1,30 @@
+//!
+//! The token literals.
+//!
+
+///
+/// An integer literal, kept in its original radix so later stages can report the radix the
+/// programmer wrote it in.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegerLiteral {
+    Decimal { value: String },
+    Hexadecimal { value: String },
+}
+
+impl IntegerLiteral {
+    pub fn new_decimal(value: String) -> Self {
+        Self::Decimal { value }
+    }
+
+    pub fn new_hexadecimal(value: String) -> Self {
+        Self::Hexadecimal { value }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Integer(IntegerLiteral),
+    Boolean(bool),
+    String(String),
+}
@@ -0,0 +1,38 @@
+//!
+//! The token location.
+//!
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column_start: usize,
+    /// One past the last column the offending token/expression occupies, so
+    /// `column_end - column_start` is the span width. Diagnostics use this to
+    /// underline the whole span rather than a single coordinate.
+    pub column_end: usize,
+}
+
+impl Location {
+    /// A single-character span starting at `column_start`, for call sites
+    /// that have not been taught their token's width yet.
+    pub fn new(line: usize, column_start: usize) -> Self {
+        Self::new_with_length(line, column_start, 1)
+    }
+
+    /// A span of `length` columns starting at `column_start`.
+    pub fn new_with_length(line: usize, column_start: usize, length: usize) -> Self {
+        Self {
+            line,
+            column_start,
+            column_end: column_start + length.max(1),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}:{})", self.line, self.column_start)
+    }
+}
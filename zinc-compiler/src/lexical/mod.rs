@@ -0,0 +1,31 @@
+//!
+//! The lexical analyzer.
+//!
+//! Tokenizing used to be a hand-rolled character-by-character tokenizer with one branch
+//! per operator and keyword. The `Symbol`/`Keyword` enums and their `scan_symbol`/
+//! `scan_keyword` longest-match functions below are generated at build time from the
+//! declarative table in `lexical.in` (see `build.rs`), turning "add an operator" into
+//! "add a line to a table" instead of "add a branch to a state machine". `stream` drives
+//! those two functions alongside the scanners for the patterns that aren't fixed strings
+//! (whitespace, comments, identifiers, integer literals).
+//!
+
+pub mod error;
+pub mod lexeme;
+pub mod literal;
+pub mod location;
+pub mod stream;
+pub mod token;
+
+pub use self::error::Error;
+pub use self::lexeme::Identifier;
+pub use self::lexeme::Lexeme;
+pub use self::literal::IntegerLiteral;
+pub use self::literal::Literal;
+pub use self::location::Location;
+pub use self::stream::TokenStream;
+pub use self::token::Token;
+
+// `Symbol`, `Keyword`, `scan_symbol`, and `scan_keyword` are generated from `lexical.in` by
+// `build.rs`. Do not hand-edit them; edit the table instead.
+include!(concat!(env!("OUT_DIR"), "/lexical_tables.rs"));
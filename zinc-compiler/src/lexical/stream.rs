@@ -0,0 +1,174 @@
+//!
+//! The token stream.
+//!
+
+use crate::lexical::Error as LexicalError;
+use crate::lexical::Identifier;
+use crate::lexical::IntegerLiteral;
+use crate::lexical::Lexeme;
+use crate::lexical::Literal;
+use crate::lexical::Location;
+use crate::lexical::Token;
+
+///
+/// Scans a `.zn` source into a `Token` at a time.
+///
+/// The old hand-rolled tokenizer branched on individual characters for every operator and
+/// keyword. Here, everything that can be expressed as a fixed pattern -- symbols and
+/// keywords -- is resolved by the `scan_symbol`/`scan_keyword` functions `build.rs`
+/// generates from the declarative table in `lexical.in`; this struct only has to drive
+/// that table plus the handful of scanners (whitespace, comments, identifiers, integer
+/// literals) that cannot be expressed as fixed strings. `Location` tracking is done per
+/// character consumed, so it stays byte-exact with the old scanner's output.
+///
+pub struct TokenStream<'a> {
+    source: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> TokenStream<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn next(&mut self) -> Result<Token, LexicalError> {
+        self.skip_trivia()?;
+
+        let line = self.line;
+        let column_start = self.column;
+        let location = Location::new(line, column_start);
+        let rest = &self.source[self.offset..];
+
+        if rest.is_empty() {
+            return Ok(Token::new(Lexeme::Eof, location));
+        }
+
+        if let Some((symbol, length)) = crate::lexical::scan_symbol(rest) {
+            self.advance(length);
+            return Ok(Token::new(
+                Lexeme::Symbol(symbol),
+                Location::new_with_length(line, column_start, length),
+            ));
+        }
+
+        let mut chars = rest.chars();
+        let first = chars.next().expect("rest is non-empty");
+
+        if first.is_ascii_digit() {
+            let lexeme = self.scan_integer(rest);
+            let length = self.column - column_start;
+            return Ok(Token::new(
+                lexeme,
+                Location::new_with_length(line, column_start, length),
+            ));
+        }
+
+        if first == '_' || first.is_alphabetic() {
+            let lexeme = self.scan_word(rest);
+            let length = self.column - column_start;
+            return Ok(Token::new(
+                lexeme,
+                Location::new_with_length(line, column_start, length),
+            ));
+        }
+
+        Err(LexicalError::UnknownCharacter {
+            location,
+            character: first,
+        })
+    }
+
+    ///
+    /// Advances past whitespace and `//` line comments, both of which are irrelevant to
+    /// every parser downstream and are dropped instead of being turned into tokens.
+    ///
+    fn skip_trivia(&mut self) -> Result<(), LexicalError> {
+        loop {
+            let rest = &self.source[self.offset..];
+
+            if let Some(whitespace) = rest.find(|character: char| !character.is_whitespace()) {
+                self.advance(whitespace);
+            } else {
+                self.advance(rest.len());
+                return Ok(());
+            }
+
+            let rest = &self.source[self.offset..];
+            if rest.starts_with("//") {
+                let length = rest.find('\n').unwrap_or(rest.len());
+                self.advance(length);
+                continue;
+            }
+
+            return Ok(());
+        }
+    }
+
+    ///
+    /// Scans a run of digits (and, for a `0x` prefix, hex digits) into an `IntegerLiteral`.
+    ///
+    fn scan_integer(&mut self, rest: &str) -> Lexeme {
+        if rest.starts_with("0x") {
+            let length = 2 + rest[2..]
+                .find(|character: char| !character.is_ascii_hexdigit())
+                .unwrap_or(rest.len() - 2);
+            let value = rest[..length].to_owned();
+            self.advance(length);
+            return Lexeme::Literal(Literal::Integer(IntegerLiteral::new_hexadecimal(value)));
+        }
+
+        let length = rest
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let value = rest[..length].to_owned();
+        self.advance(length);
+        Lexeme::Literal(Literal::Integer(IntegerLiteral::new_decimal(value)))
+    }
+
+    ///
+    /// Scans a run of identifier characters, then resolves it against the generated
+    /// keyword table, falling back to a plain identifier.
+    ///
+    fn scan_word(&mut self, rest: &str) -> Lexeme {
+        let length = rest
+            .find(|character: char| character != '_' && !character.is_alphanumeric())
+            .unwrap_or(rest.len());
+        let word = &rest[..length];
+
+        // `true`/`false` are matched by the generated keyword table like any other reserved
+        // word; the syntax layer is what turns `Keyword::True`/`Keyword::False` into a
+        // `Literal::Boolean` operand.
+        let lexeme = match crate::lexical::scan_keyword(word) {
+            Some(keyword) => Lexeme::Keyword(keyword),
+            None => Lexeme::Identifier(Identifier::new(word.to_owned())),
+        };
+
+        self.advance(length);
+        lexeme
+    }
+
+    ///
+    /// Consumes `length` bytes from the front of the remaining source, updating
+    /// `line`/`column` so `Location` stays in step regardless of how many newlines were
+    /// crossed.
+    ///
+    fn advance(&mut self, length: usize) {
+        for character in self.source[self.offset..self.offset + length].chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+
+        self.offset += length;
+    }
+}
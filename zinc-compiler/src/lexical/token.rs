@@ -0,0 +1,18 @@
+//!
+//! The lexical token.
+//!
+
+use crate::lexical::Lexeme;
+use crate::lexical::Location;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub lexeme: Lexeme,
+    pub location: Location,
+}
+
+impl Token {
+    pub fn new(lexeme: Lexeme, location: Location) -> Self {
+        Self { lexeme, location }
+    }
+}
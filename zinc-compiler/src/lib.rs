@@ -2,6 +2,7 @@
 //! The Zinc compiler library.
 //!
 
+pub(crate) mod diagnostic;
 pub(crate) mod error;
 pub(crate) mod generator;
 pub(crate) mod lexical;
@@ -14,6 +15,8 @@ pub use self::error::Error;
 pub use self::generator::bytecode::entry::Entry;
 pub use self::generator::bytecode::Bytecode;
 pub use self::generator::program::Program;
+pub use self::lexical::Lexeme;
+pub use self::lexical::TokenStream;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 pub use self::semantic::scope::Scope;
 pub use self::source::error::Error as SourceError;
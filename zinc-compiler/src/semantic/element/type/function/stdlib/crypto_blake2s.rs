@@ -0,0 +1,127 @@
+//!
+//! The semantic analyzer standard library `std::crypto::blake2s` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_bytecode::FunctionIdentifier;
+
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+/// The bit length of a `blake2s` digest.
+pub const HASH_LENGTH: usize = 256;
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub location: Option<Location>,
+    pub builtin_identifier: FunctionIdentifier,
+    pub identifier: &'static str,
+    pub return_type: Box<Type>,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_PREIMAGE: usize = 0;
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    pub fn new(builtin_identifier: FunctionIdentifier) -> Self {
+        Self {
+            location: None,
+            builtin_identifier,
+            identifier: "blake2s",
+            return_type: Box::new(Type::array(
+                Some(Location::default()),
+                Type::boolean(None),
+                HASH_LENGTH,
+            )),
+        }
+    }
+
+    pub fn call(
+        self,
+        location: Option<Location>,
+        actual_elements: Vec<Element>,
+    ) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(crate::panic::LOCATION_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_PREIMAGE) {
+            Some((Type::Array(array), location)) => match (array.r#type.deref(), array.size) {
+                (Type::Boolean(_), size) if size > 0 && size % zinc_const::bitlength::BYTE == 0 => {
+                }
+                (r#type, size) => {
+                    return Err(Error::ArgumentType {
+                        location: location.expect(crate::panic::LOCATION_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: "preimage".to_owned(),
+                        position: Self::ARGUMENT_INDEX_PREIMAGE + 1,
+                        expected: format!(
+                            "[bool; N], N > 0, N % {} == 0",
+                            zinc_const::bitlength::BYTE
+                        ),
+                        found: format!("array [{}; {}]", r#type, size),
+                    })
+                }
+            },
+            Some((r#type, location)) => {
+                return Err(Error::ArgumentType {
+                    location: location.expect(crate::panic::LOCATION_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "preimage".to_owned(),
+                    position: Self::ARGUMENT_INDEX_PREIMAGE + 1,
+                    expected: format!("[bool; N], N > 0, N % {} == 0", zinc_const::bitlength::BYTE),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location: location.expect(crate::panic::LOCATION_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location: location.expect(crate::panic::LOCATION_ALWAYS_EXISTS),
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+            });
+        }
+
+        Ok(*self.return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "crypto::{}(preimage: [bool: N]) -> {}",
+            self.identifier, self.return_type,
+        )
+    }
+}
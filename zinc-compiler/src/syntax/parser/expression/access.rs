@@ -8,322 +8,157 @@ use std::rc::Rc;
 use crate::error::Error;
 use crate::lexical;
 use crate::lexical::Lexeme;
-use crate::lexical::Symbol;
 use crate::lexical::Token;
 use crate::lexical::TokenStream;
 use crate::syntax::error::Error as SyntaxError;
-use crate::syntax::parser::expression::path::Parser as PathOperandParser;
+use crate::syntax::parser::expression::synchronize;
 use crate::syntax::parser::expression::terminal::list::Parser as ExpressionListParser;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
+use crate::syntax::parser::expression::path::Parser as PathOperandParser;
 use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
 use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
-use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::member_integer::builder::Builder as MemberIntegerBuilder;
 use crate::syntax::tree::member_string::builder::Builder as MemberStringBuilder;
 
-#[derive(Debug, Clone, Copy)]
-pub enum State {
-    PathOperand,
-    ExclamationMarkOrNext,
-    AccessOrCallOrEnd,
-    IndexExpression,
-    BracketSquareRight,
-    FieldDescriptor,
-    ArgumentList,
-    ParenthesisRight,
-}
+///
+/// Parses the atomic operand that an access chain starts from. The `Index`, `Call`, and
+/// `Field` continuations that used to follow it in its own state machine are now `led`
+/// handlers (see [`parse_index`], [`parse_call`], [`parse_field`]) driven by the Pratt
+/// loop in the parent `expression` module.
+///
+#[derive(Default)]
+pub struct Parser {}
 
-impl Default for State {
-    fn default() -> Self {
-        State::PathOperand
+impl Parser {
+    pub fn parse(
+        self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ExpressionTree, Option<Token>), Error> {
+        PathOperandParser::default().parse(stream, initial)
     }
 }
 
-#[derive(Default)]
-pub struct Parser {
-    state: State,
-    next: Option<Token>,
-    builder: ExpressionTreeBuilder,
+///
+/// Parses the `[ expression ]` continuation of an `Index` operator, feeding the indexed
+/// expression into `builder`. Returns the token following the closing bracket.
+///
+/// On a missing `]`, the mismatch is recorded in `errors` rather than aborting the parse:
+/// an empty-list placeholder stands in for the index expression, and the caller resumes
+/// from the next synchronizing token.
+///
+pub fn parse_index(
+    stream: Rc<RefCell<TokenStream>>,
+    builder: &mut ExpressionTreeBuilder,
+    errors: &mut Vec<Error>,
+) -> Result<Option<Token>, Error> {
+    let (expression, mut sub_errors, next) = ExpressionParser::default().parse(stream.clone(), None)?;
+    errors.append(&mut sub_errors);
+    builder.eat(expression);
 
-    is_indexed: bool,
+    match crate::syntax::parser::take_or_next(next, stream.clone())? {
+        Token {
+            lexeme: Lexeme::Symbol(lexical::Symbol::BracketSquareRight),
+            ..
+        } => Ok(None),
+        token => {
+            errors.push(Error::Syntax(SyntaxError::expected_one_of_or_operator(
+                token.location,
+                vec!["]"],
+                token.lexeme.clone(),
+                None,
+            )));
+            builder.eat_operand(ExpressionOperand::List(Vec::new()), token.location);
+            Ok(Some(synchronize(token, stream)?))
+        }
+    }
 }
 
-impl Parser {
-    pub fn parse(
-        mut self,
-        stream: Rc<RefCell<TokenStream>>,
-        mut initial: Option<Token>,
-    ) -> Result<(ExpressionTree, Option<Token>), Error> {
-        loop {
-            match self.state {
-                State::PathOperand => {
-                    let (expression, next) =
-                        PathOperandParser::default().parse(stream.clone(), initial.take())?;
-                    self.next = next;
-                    self.builder.eat(expression);
-                    self.state = State::ExclamationMarkOrNext;
-                }
-                State::ExclamationMarkOrNext => {
-                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::ExclamationMark),
-                            ..
-                        } => {
-                            // self.auxiliary = Some((location, ExpressionAuxiliary::CallBuiltIn));
-                            // TODO
-                        }
-                        token => self.next = Some(token),
-                    }
-                    self.state = State::AccessOrCallOrEnd;
-                }
-                State::AccessOrCallOrEnd => {
-                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::BracketSquareLeft),
-                            location,
-                        } => {
-                            self.builder
-                                .eat_operator(ExpressionOperator::Index, location);
-                            self.is_indexed = true;
-                            self.state = State::IndexExpression;
-                        }
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
-                            location,
-                        } => {
-                            self.builder
-                                .eat_operator(ExpressionOperator::Call, location);
-                            self.state = State::ArgumentList;
-                        }
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::Dot),
-                            location,
-                        } => {
-                            self.builder
-                                .eat_operator(ExpressionOperator::Field, location);
-                            self.is_indexed = true;
-                            self.state = State::FieldDescriptor;
-                        }
-                        token => {
-                            return Ok((self.builder.finish(), Some(token)));
-                        }
-                    }
-                }
-                State::IndexExpression => {
-                    let (expression, next) =
-                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
-                    self.next = next;
-                    self.builder.eat(expression);
-                    self.state = State::BracketSquareRight;
-                }
-                State::BracketSquareRight => {
-                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::BracketSquareRight),
-                            ..
-                        } => {
-                            self.state = State::AccessOrCallOrEnd;
-                        }
-                        Token { lexeme, location } => {
-                            return Err(Error::Syntax(SyntaxError::expected_one_of_or_operator(
-                                location,
-                                vec!["]"],
-                                lexeme,
-                                None,
-                            )))
-                        }
-                    }
-                }
-                State::FieldDescriptor => {
-                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme:
-                                Lexeme::Literal(lexical::Literal::Integer(
-                                    literal @ lexical::IntegerLiteral::Decimal { .. },
-                                )),
-                            location,
-                        } => {
-                            let mut builder = MemberIntegerBuilder::default();
-                            builder.set_location(location);
-                            builder.set_literal(IntegerLiteral::new(location, literal));
-                            self.builder.eat_operand(
-                                ExpressionOperand::MemberInteger(builder.finish()),
-                                location,
-                            );
-                            self.state = State::AccessOrCallOrEnd;
-                        }
-                        Token {
-                            lexeme: Lexeme::Identifier(identifier),
-                            location,
-                        } => {
-                            let mut builder = MemberStringBuilder::default();
-                            builder.set_location(location);
-                            builder.set_name(identifier.name);
-                            self.builder.eat_operand(
-                                ExpressionOperand::MemberString(builder.finish()),
-                                location,
-                            );
-                            self.state = State::AccessOrCallOrEnd;
-                        }
-                        Token { lexeme, location } => {
-                            return Err(Error::Syntax(SyntaxError::expected_field_identifier(
-                                location, lexeme, None,
-                            )))
-                        }
-                    }
-                }
-                State::ArgumentList => {
-                    let (expressions, location, next) =
-                        ExpressionListParser::default().parse(stream.clone(), None)?;
-                    self.next = next;
-                    self.builder
-                        .eat_operand(ExpressionOperand::List(expressions), location);
-                    self.state = State::ParenthesisRight;
-                }
-                State::ParenthesisRight => {
-                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
-                            ..
-                        } => {
-                            self.state = State::AccessOrCallOrEnd;
-                        }
-                        Token { lexeme, location } => {
-                            return Err(Error::Syntax(SyntaxError::expected_one_of_or_operator(
-                                location,
-                                vec![")"],
-                                lexeme,
-                                None,
-                            )))
-                        }
-                    }
-                }
-            }
+///
+/// Parses the `( arguments )` continuation of a `Call` operator, feeding the argument list
+/// into `builder`. Returns the token following the closing parenthesis.
+///
+/// On a missing `)`, the mismatch is recorded in `errors` rather than aborting the parse:
+/// an empty argument list stands in for the malformed call, and the caller resumes from
+/// the next synchronizing token.
+///
+pub fn parse_call(
+    stream: Rc<RefCell<TokenStream>>,
+    builder: &mut ExpressionTreeBuilder,
+    errors: &mut Vec<Error>,
+) -> Result<Option<Token>, Error> {
+    let (expressions, location, next) = ExpressionListParser::default().parse(stream.clone(), None)?;
+    builder.eat_operand(ExpressionOperand::List(expressions), location);
+
+    match crate::syntax::parser::take_or_next(next, stream.clone())? {
+        Token {
+            lexeme: Lexeme::Symbol(lexical::Symbol::ParenthesisRight),
+            ..
+        } => Ok(None),
+        token => {
+            errors.push(Error::Syntax(SyntaxError::expected_one_of_or_operator(
+                token.location,
+                vec![")"],
+                token.lexeme.clone(),
+                None,
+            )));
+            Ok(Some(synchronize(token, stream)?))
         }
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use std::cell::RefCell;
-//     use std::rc::Rc;
-//
-//     use super::Error;
-//     use super::Parser;
-//     use crate::lexical;
-//     use crate::lexical::Lexeme;
-//     use crate::lexical::Location;
-//     use crate::lexical::Symbol;
-//     use crate::lexical::Token;
-//     use crate::lexical::TokenStream;
-//     use crate::syntax::error::Error as SyntaxError;
-//     use crate::syntax::tree::expression::auxiliary::Auxiliary as ExpressionAuxiliary;
-//     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
-//     use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
-//     use crate::syntax::tree::identifier::Identifier;
-//     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
-//     use crate::syntax::tree::member_integer::MemberInteger;
-//     use crate::syntax::tree::member_string::MemberString;
-//
-//     #[test]
-//     fn ok() {
-//         let input = r#"array[42].25.value"#;
-//
-//         let expected = Ok((
-//             Expression::new(
-//                 Location::new(1, 1),
-//                 vec![
-//                     ExpressionElement::new(
-//                         Location::new(1, 1),
-//                         ExpressionObject::Operand(ExpressionOperand::Identifier(Identifier::new(
-//                             Location::new(1, 1),
-//                             "array".to_owned(),
-//                         ))),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 7),
-//                         ExpressionObject::Operand(ExpressionOperand::LiteralInteger(
-//                             IntegerLiteral::new(
-//                                 Location::new(1, 7),
-//                                 lexical::IntegerLiteral::new_decimal("42".to_owned()),
-//                             ),
-//                         )),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 6),
-//                         ExpressionObject::Operator(ExpressionOperator::Index),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 11),
-//                         ExpressionObject::Operand(ExpressionOperand::MemberInteger(
-//                             MemberInteger::new(
-//                                 Location::new(1, 11),
-//                                 IntegerLiteral::new(
-//                                     Location::new(1, 11),
-//                                     lexical::IntegerLiteral::new_decimal("25".to_owned()),
-//                                 ),
-//                             ),
-//                         )),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 10),
-//                         ExpressionObject::Operator(ExpressionOperator::Field),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 14),
-//                         ExpressionObject::Operand(ExpressionOperand::MemberString(
-//                             MemberString::new(Location::new(1, 14), "value".to_owned()),
-//                         )),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 13),
-//                         ExpressionObject::Operator(ExpressionOperator::Field),
-//                     ),
-//                     ExpressionElement::new(
-//                         Location::new(1, 19),
-//                         ExpressionObject::Auxiliary(ExpressionAuxiliary::PlaceEnd),
-//                     ),
-//                 ],
-//             ),
-//             Some(Token::new(Lexeme::Eof, Location::new(1, 19))),
-//         ));
-//
-//         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
-//
-//         assert_eq!(result, expected);
-//     }
-//
-//     #[test]
-//     fn error_expected_bracket_square_right() {
-//         let input = r#"array[42)"#;
-//
-//         let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
-//             Location::new(1, 9),
-//             vec!["]"],
-//             Lexeme::Symbol(Symbol::ParenthesisRight),
-//             None,
-//         )));
-//
-//         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
-//
-//         assert_eq!(result, expected);
-//     }
-//
-//     #[test]
-//     fn error_expected_parenthesis_right() {
-//         let input = r#"sort(42, 69]"#;
-//
-//         let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
-//             Location::new(1, 12),
-//             vec![")"],
-//             Lexeme::Symbol(Symbol::BracketSquareRight),
-//             None,
-//         )));
-//
-//         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
-//
-//         assert_eq!(result, expected);
-//     }
-// }
+///
+/// Parses the member descriptor following a `Field` operator's `.`, feeding it into
+/// `builder` as a tuple index (`MemberInteger`) or a named field (`MemberString`).
+///
+/// On a missing descriptor, the mismatch is recorded in `errors` rather than aborting the
+/// parse: no member operand is emitted, and the caller resumes from the next synchronizing
+/// token.
+///
+pub fn parse_field(
+    stream: Rc<RefCell<TokenStream>>,
+    builder: &mut ExpressionTreeBuilder,
+    errors: &mut Vec<Error>,
+) -> Result<Option<Token>, Error> {
+    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+        Token {
+            lexeme:
+                Lexeme::Literal(lexical::Literal::Integer(
+                    literal @ lexical::IntegerLiteral::Decimal { .. },
+                )),
+            location,
+        } => {
+            let mut member_builder = MemberIntegerBuilder::default();
+            member_builder.set_location(location);
+            member_builder.set_literal(IntegerLiteral::new(location, literal));
+            builder.eat_operand(
+                ExpressionOperand::MemberInteger(member_builder.finish()),
+                location,
+            );
+            Ok(None)
+        }
+        Token {
+            lexeme: Lexeme::Identifier(identifier),
+            location,
+        } => {
+            let mut member_builder = MemberStringBuilder::default();
+            member_builder.set_location(location);
+            member_builder.set_name(identifier.name);
+            builder.eat_operand(
+                ExpressionOperand::MemberString(member_builder.finish()),
+                location,
+            );
+            Ok(None)
+        }
+        token => {
+            errors.push(Error::Syntax(SyntaxError::expected_field_identifier(
+                token.location,
+                token.lexeme.clone(),
+                None,
+            )));
+            Ok(Some(synchronize(token, stream)?))
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//!
+//! The expression parser.
+//!
+
+mod access;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::Keyword;
+use crate::lexical::Lexeme;
+use crate::lexical::Location;
+use crate::lexical::Symbol;
+use crate::lexical::Token;
+use crate::lexical::TokenStream;
+use crate::syntax::parser::expression::access::Parser as AccessOperandParser;
+use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+
+/// The binding power accepted by the entry point, i.e. "parse anything".
+const BP_LOWEST: u8 = 0;
+
+/// The binding power of a prefix unary operator (`-`, `!`, `~`). Binds tighter than every
+/// binary operator, but looser than the postfix operators, so `-a.b` is `-(a.b)` and
+/// `-a as u8` is `(-a) as u8`.
+const BP_UNARY: u8 = 21;
+
+/// The binding power of the postfix operators (`Index`, `Call`, `Field`). Always wins the
+/// `lbp < min_bp` check, so they bind tighter than anything a nud can hand back to the loop.
+const BP_POSTFIX: u8 = 255;
+
+/// Symbols that panic-mode recovery treats as safe resumption points: closing delimiters
+/// and the separators between elements or statements.
+const SYNCHRONIZING_SYMBOLS: [Symbol; 4] = [
+    Symbol::BracketSquareRight,
+    Symbol::ParenthesisRight,
+    Symbol::Semicolon,
+    Symbol::Comma,
+];
+
+/// Keywords that panic-mode recovery treats as safe resumption points, since they can only
+/// appear at the start of a new statement.
+const SYNCHRONIZING_KEYWORDS: [Keyword; 4] = [Keyword::Let, Keyword::If, Keyword::While, Keyword::For];
+
+///
+/// The Pratt (precedence-climbing) expression parser.
+///
+/// Where the old implementation chained one hand-written state machine per precedence
+/// level, this one is a single loop driven by a binding-power table: parse a prefix
+/// operand (`nud`), then repeatedly look up the binding power of the next operator and
+/// either fold it in or stop, depending on how it compares to `min_bp`.
+///
+/// Unlike the old chain, this parser does not abort on the first unexpected token. The
+/// postfix continuations in [`access`] recover from a malformed `Index`/`Call`/`Field` tail
+/// by recording the diagnostic in `errors`, feeding a placeholder operand to the builder,
+/// and skipping tokens until a synchronizing one is found, so a file with several mistakes
+/// is reported in a single pass instead of stopping at the first.
+///
+#[derive(Default)]
+pub struct Parser {
+    builder: ExpressionTreeBuilder,
+    errors: Vec<Error>,
+}
+
+impl Parser {
+    pub fn parse(
+        self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ExpressionTree, Vec<Error>, Option<Token>), Error> {
+        self.parse_expression(stream, BP_LOWEST, initial)
+    }
+
+    ///
+    /// Parses an expression whose operators all bind at least as tightly as `min_bp`,
+    /// stopping and handing the unconsumed operator back to the caller otherwise.
+    ///
+    /// Returns the accumulated recoverable diagnostics alongside the (possibly partial)
+    /// tree; only a failure of the token stream itself is propagated as `Err`.
+    ///
+    fn parse_expression(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        min_bp: u8,
+        initial: Option<Token>,
+    ) -> Result<(ExpressionTree, Vec<Error>, Option<Token>), Error> {
+        let mut next = self.parse_nud(stream.clone(), initial)?;
+
+        loop {
+            let token = crate::syntax::parser::take_or_next(next.take(), stream.clone())?;
+
+            let (operator, location, lbp, rbp) = match Self::led_binding_power(&token) {
+                Some(binding_power) => binding_power,
+                None => {
+                    next = Some(token);
+                    break;
+                }
+            };
+
+            if lbp < min_bp {
+                next = Some(token);
+                break;
+            }
+
+            next = match operator {
+                ExpressionOperator::Index => {
+                    self.builder.eat_operator(operator, location);
+                    access::parse_index(stream.clone(), &mut self.builder, &mut self.errors)?
+                }
+                ExpressionOperator::Call => {
+                    self.builder.eat_operator(operator, location);
+                    access::parse_call(stream.clone(), &mut self.builder, &mut self.errors)?
+                }
+                ExpressionOperator::Field => {
+                    self.builder.eat_operator(operator, location);
+                    access::parse_field(stream.clone(), &mut self.builder, &mut self.errors)?
+                }
+                operator => {
+                    let (rhs, mut rhs_errors, next) =
+                        Self::default().parse_expression(stream.clone(), rbp, None)?;
+                    self.errors.append(&mut rhs_errors);
+                    self.builder.eat(rhs);
+                    self.builder.eat_operator(operator, location);
+                    next
+                }
+            };
+        }
+
+        Ok((self.builder.finish(), self.errors, next))
+    }
+
+    ///
+    /// Parses a prefix operand: either a unary operator applied to a recursively parsed
+    /// operand, or a terminal/access operand with its leading `!`-call-marker absorbed.
+    ///
+    fn parse_nud(
+        &mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<Option<Token>, Error> {
+        let token = crate::syntax::parser::take_or_next(initial, stream.clone())?;
+
+        let operator = match token.lexeme {
+            Lexeme::Symbol(Symbol::Minus) => Some(ExpressionOperator::Negation),
+            Lexeme::Symbol(Symbol::ExclamationMark) => Some(ExpressionOperator::Not),
+            Lexeme::Symbol(Symbol::Tilde) => Some(ExpressionOperator::BitwiseNot),
+            _ => None,
+        };
+
+        match operator {
+            Some(operator) => {
+                let (operand, mut errors, next) =
+                    Self::default().parse_expression(stream, BP_UNARY, None)?;
+                self.errors.append(&mut errors);
+                self.builder.eat(operand);
+                self.builder.eat_operator(operator, token.location);
+                Ok(next)
+            }
+            None => {
+                let (operand, next) = AccessOperandParser::default().parse(stream, Some(token))?;
+                self.builder.eat(operand);
+                Ok(next)
+            }
+        }
+    }
+
+    ///
+    /// Looks up the `(operator, location, lbp, rbp)` of `token` if it can continue an
+    /// expression as a binary or postfix operator, i.e. is a valid `led`.
+    ///
+    /// `rbp` is `lbp` for `Casting`, since `as` is the only binary-shaped operator here
+    /// that is not left-associative in the usual sense: its right-hand side is a type, not
+    /// a sub-expression, and is parsed separately by the caller. For every other operator,
+    /// `rbp = lbp + 1` enforces left associativity.
+    ///
+    fn led_binding_power(token: &Token) -> Option<(ExpressionOperator, Location, u8, u8)> {
+        let location = token.location;
+
+        let (operator, lbp) = match token.lexeme {
+            Lexeme::Symbol(Symbol::DoubleVerticalBar) => (ExpressionOperator::Or, 3),
+            Lexeme::Symbol(Symbol::DoubleAmpersand) => (ExpressionOperator::And, 4),
+            Lexeme::Symbol(Symbol::DoubleEquals) => (ExpressionOperator::Equal, 5),
+            Lexeme::Symbol(Symbol::ExclamationMarkEquals) => (ExpressionOperator::NotEqual, 5),
+            Lexeme::Symbol(Symbol::GreaterEquals) => (ExpressionOperator::GreaterEqual, 5),
+            Lexeme::Symbol(Symbol::LesserEquals) => (ExpressionOperator::LesserEqual, 5),
+            Lexeme::Symbol(Symbol::Greater) => (ExpressionOperator::Greater, 5),
+            Lexeme::Symbol(Symbol::Lesser) => (ExpressionOperator::Lesser, 5),
+            Lexeme::Symbol(Symbol::VerticalBar) => (ExpressionOperator::BitwiseOr, 6),
+            Lexeme::Symbol(Symbol::Circumflex) => (ExpressionOperator::BitwiseXor, 7),
+            Lexeme::Symbol(Symbol::Ampersand) => (ExpressionOperator::BitwiseAnd, 8),
+            Lexeme::Symbol(Symbol::DoubleLesser) => (ExpressionOperator::BitwiseShiftLeft, 9),
+            Lexeme::Symbol(Symbol::DoubleGreater) => (ExpressionOperator::BitwiseShiftRight, 9),
+            Lexeme::Symbol(Symbol::Plus) => (ExpressionOperator::Addition, 10),
+            Lexeme::Symbol(Symbol::Minus) => (ExpressionOperator::Subtraction, 10),
+            Lexeme::Symbol(Symbol::Asterisk) => (ExpressionOperator::Multiplication, 11),
+            Lexeme::Symbol(Symbol::Slash) => (ExpressionOperator::Division, 11),
+            Lexeme::Symbol(Symbol::Percent) => (ExpressionOperator::Remainder, 11),
+            Lexeme::Keyword(Keyword::As) => (ExpressionOperator::Casting, 12),
+            Lexeme::Symbol(Symbol::BracketSquareLeft) => (ExpressionOperator::Index, BP_POSTFIX),
+            Lexeme::Symbol(Symbol::ParenthesisLeft) => (ExpressionOperator::Call, BP_POSTFIX),
+            Lexeme::Symbol(Symbol::Dot) => (ExpressionOperator::Field, BP_POSTFIX),
+            _ => return None,
+        };
+
+        let rbp = if operator == ExpressionOperator::Casting || lbp == BP_POSTFIX {
+            lbp
+        } else {
+            lbp + 1
+        };
+
+        Some((operator, location, lbp, rbp))
+    }
+}
+
+///
+/// Whether `token` is a safe point to resume parsing after a panic-mode recovery skip: a
+/// closing delimiter, a separator, the end of the stream, or a statement-start keyword.
+///
+fn is_synchronizing(token: &Token) -> bool {
+    match token.lexeme {
+        Lexeme::Eof => true,
+        Lexeme::Symbol(symbol) => SYNCHRONIZING_SYMBOLS.contains(&symbol),
+        Lexeme::Keyword(keyword) => SYNCHRONIZING_KEYWORDS.contains(&keyword),
+        _ => false,
+    }
+}
+
+///
+/// Discards tokens from `stream`, starting with the already-taken `token`, until one of
+/// them is a [`is_synchronizing`] token, then returns it unconsumed so the caller can
+/// resume its state machine from a known-good position.
+///
+pub(crate) fn synchronize(
+    mut token: Token,
+    stream: Rc<RefCell<TokenStream>>,
+) -> Result<Token, Error> {
+    while !is_synchronizing(&token) {
+        token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+    }
+
+    Ok(token)
+}
@@ -0,0 +1,50 @@
+//!
+//! The diagnostic sink a parse session accumulates errors into, so a parser can keep going
+//! after a syntax error (see the multi-error recovery in the expression parser) instead of
+//! bailing out on the first one.
+//!
+
+use crate::location::Location;
+
+///
+/// A single reported diagnostic, detached from whatever error type produced it so the sink
+/// itself does not need to depend on the parser or semantic analyzer crates.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub location: Location,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(location: Location, message: String) -> Self {
+        Self { location, message }
+    }
+}
+
+///
+/// Accumulates diagnostics for a compilation session, shared between the lexer, the parser,
+/// and anything downstream that wants to inspect what went wrong without unwinding the parse.
+///
+#[derive(Debug, Default)]
+pub struct ErrorSink {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ErrorSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+}
@@ -0,0 +1,15 @@
+//!
+//! Definitions shared between the AST (`zinc-ast`) and the lexer/parser, so neither has to
+//! depend on the other just to agree on what a source location or a diagnostic is.
+//!
+
+pub mod error_sink;
+pub mod location;
+pub mod source_map;
+
+pub use self::error_sink::Diagnostic;
+pub use self::error_sink::ErrorSink;
+pub use self::location::Location;
+pub use self::source_map::FileId;
+pub use self::source_map::SourceFile;
+pub use self::source_map::SourceMap;
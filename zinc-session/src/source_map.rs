@@ -0,0 +1,58 @@
+//!
+//! The source map: the set of files a compilation session knows about, keyed by a stable ID
+//! so a `Location` can be resolved back to file text without either the AST or the parser
+//! holding a borrowed reference to it.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+///
+/// A stable handle to one file registered in a `SourceMap`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileId(u32);
+
+///
+/// One file's path and text, as registered with a `SourceMap`.
+///
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+///
+/// The files registered so far in a compilation session. Shared by the parser (which
+/// registers files as it reads them) and any downstream tooling (formatters, linters,
+/// language-server features) that only has a `Location` and needs the original text back.
+///
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    files: HashMap<FileId, SourceFile>,
+    next_id: u32,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Registers `text` under `path`, returning the `FileId` to attach to every `Location`
+    /// produced while lexing and parsing it.
+    ///
+    pub fn register(&mut self, path: PathBuf, text: String) -> FileId {
+        let id = FileId(self.next_id);
+        self.next_id += 1;
+        self.files.insert(id, SourceFile { path, text });
+        id
+    }
+
+    ///
+    /// Looks up a previously registered file.
+    ///
+    pub fn get(&self, id: FileId) -> Option<&SourceFile> {
+        self.files.get(&id)
+    }
+}
@@ -2,8 +2,40 @@
 //! The Zinc tester arguments.
 //!
 
+use std::str::FromStr;
+
 use structopt::StructOpt;
 
+///
+/// The intermediate representation dumped by `--emit`, halting before
+/// circuit synthesis.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum EmitMode {
+    /// The `lexical::TokenStream` output.
+    Tokens,
+    /// The parsed `syntax::parser` item/expression tree.
+    Ast,
+    /// The compiled bytecode.
+    Bytecode,
+}
+
+impl FromStr for EmitMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "tokens" => Ok(Self::Tokens),
+            "ast" => Ok(Self::Ast),
+            "bytecode" => Ok(Self::Bytecode),
+            value => Err(format!(
+                "unknown `--emit` value `{}`, expected `tokens`, `ast`, or `bytecode`",
+                value
+            )),
+        }
+    }
+}
+
 ///
 /// The Zinc tester arguments.
 ///
@@ -22,6 +54,9 @@ pub struct Arguments {
     /// Runs only tests whose name contains the specified string.
     #[structopt(short = "f", long = "filter")]
     pub filter: Option<String>,
+    /// Dumps the tokens, AST, or bytecode for the filtered tests instead of running them.
+    #[structopt(long = "emit")]
+    pub emit: Option<EmitMode>,
 }
 
 impl Arguments {
@@ -0,0 +1,52 @@
+//!
+//! The storage Merkle leaf hash function selection.
+//!
+//! `Leaf` used to hardcode `sha256`; this makes the hash function a
+//! parameter so a Pedersen hash can be used instead, cutting the per-leaf
+//! constraint count by an order of magnitude.
+//!
+
+use franklin_crypto::jubjub::JubjubEngine;
+
+use crate::core::contract::storage::pedersen;
+use crate::core::contract::storage::sha256;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The hash function used to commit storage leaves and their authentication
+/// paths.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleHasher {
+    /// The general-purpose, constraint-expensive SHA-256.
+    Sha256,
+    /// The cheaper Pedersen hash over JubJub.
+    Pedersen,
+}
+
+impl MerkleHasher {
+    ///
+    /// The digest bit length produced by this hasher.
+    ///
+    pub fn hash_length(self) -> usize {
+        match self {
+            Self::Sha256 => zinc_const::BITLENGTH_SHA256_HASH,
+            Self::Pedersen => pedersen::BITLENGTH,
+        }
+    }
+
+    ///
+    /// Hashes `values` with the selected hasher, returning the digest as
+    /// big-endian bytes.
+    ///
+    pub fn hash<E>(self, values: Vec<Scalar<E>>, params: &E::Params) -> Vec<u8>
+    where
+        E: IEngine + JubjubEngine,
+    {
+        match self {
+            Self::Sha256 => sha256::leaf_value_hash::<E>(values),
+            Self::Pedersen => pedersen::leaf_value_hash::<E>(values, params),
+        }
+    }
+}
@@ -1,34 +1,75 @@
-use crate::core::contract::storage::sha256;
+use franklin_crypto::jubjub::JubjubEngine;
+
+use crate::core::contract::storage::hasher::MerkleHasher;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
+///
+/// The payload a storage leaf carries, shaped by which collection owns the
+/// slot: a plain array stores one flat value, while an `MTreeMap` stores the
+/// key/value pairs that happen to have hashed into this slot.
+///
+#[derive(Debug)]
+pub enum LeafVariant<E: IEngine> {
+    /// An `MTreeMap` slot: every `(key, value)` pair currently stored there.
+    Map {
+        /// The pairs stored in this slot, in insertion order.
+        data: Vec<(Vec<Scalar<E>>, Vec<Scalar<E>>)>,
+    },
+    /// A plain array slot's flat value.
+    Array(Vec<Scalar<E>>),
+}
+
+impl<E: IEngine> LeafVariant<E> {
+    /// Flattens this leaf's payload into the scalar sequence the Merkle
+    /// hasher commits to: an array leaf hashes its values directly, a map
+    /// leaf hashes every `(key, value)` pair back to back, in order.
+    fn flatten(&self) -> Vec<Scalar<E>> {
+        match self {
+            Self::Array(values) => values.to_owned(),
+            Self::Map { data } => data
+                .iter()
+                .flat_map(|(key, value)| key.iter().chain(value.iter()).cloned())
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Leaf<E: IEngine> {
-    pub leaf_values: Vec<Scalar<E>>,
+    pub leaf_values: LeafVariant<E>,
     pub leaf_value_hash: Vec<bool>,
     pub authentication_path: Vec<Vec<bool>>,
 }
 
 impl<E: IEngine> Leaf<E> {
     pub fn new(
-        values: &[Scalar<E>],
+        values: LeafVariant<E>,
         authentication_path: Option<Vec<Vec<bool>>>,
         depth: usize,
-    ) -> Self {
+        hasher: MerkleHasher,
+        params: &E::Params,
+    ) -> Self
+    where
+        E: JubjubEngine,
+    {
+        let hash_length = hasher.hash_length();
+
         Self {
-            leaf_values: values.to_owned(),
             leaf_value_hash: {
                 let mut hash = vec![];
-                for i in sha256::leaf_value_hash::<E>(values.to_owned()) {
+                for i in hasher.hash(values.flatten(), params) {
                     for j in (0..zinc_const::BITLENGTH_BYTE).rev() {
                         let bit = ((i >> j) & 1u8) == 1u8;
                         hash.push(bit);
                     }
                 }
+                hash.truncate(hash_length);
                 hash
             },
+            leaf_values: values,
             authentication_path: authentication_path
-                .unwrap_or_else(|| vec![vec![false; zinc_const::BITLENGTH_SHA256_HASH]; depth]),
+                .unwrap_or_else(|| vec![vec![false; hash_length]; depth]),
         }
     }
 }
@@ -0,0 +1,77 @@
+//!
+//! The Pedersen hash over the JubJub curve, used as the cheaper alternative
+//! to `sha256` for storage Merkle leaves and authentication paths.
+//!
+//! SHA-256 costs tens of thousands of constraints per hash inside an R1CS,
+//! whereas a Pedersen hash over a small set of fixed generators costs an
+//! order of magnitude fewer, at the price of only being collision-resistant
+//! rather than a general-purpose digest. This is the scheme Zcash-style
+//! circuits use for note/leaf commitments.
+//!
+
+use franklin_crypto::jubjub::JubjubEngine;
+use franklin_crypto::pedersen_hash;
+use franklin_crypto::pedersen_hash::Personalization;
+
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+/// The bit length of a Pedersen hash digest (the `x`-coordinate of a JubJub point).
+pub const BITLENGTH: usize = 256;
+
+///
+/// Flattens `values` to their bit representation and runs the Pedersen hash
+/// over JubJub, returning the digest as big-endian bytes so callers can treat
+/// it the same way as `sha256::leaf_value_hash`.
+///
+/// The bit-level encoding performed by [`pedersen_hash::pedersen_hash`] pads
+/// the input to a multiple of 3 bits, splits it into 3-bit windows, and
+/// folds each window `(b0, b1, s)` into the signed scalar
+/// `(1 + b0 + 2*b1) * (1 - 2*s) ∈ {±1, ±2, ±3, ±4}`, which is never zero, so
+/// the fixed-base accumulation never hits the point at infinity.
+///
+pub fn leaf_value_hash<E>(values: Vec<Scalar<E>>, params: &E::Params) -> Vec<u8>
+where
+    E: IEngine + JubjubEngine,
+{
+    let bits: Vec<bool> = values
+        .into_iter()
+        .flat_map(|value| scalar_to_bits_be(&value))
+        .collect();
+
+    let point = pedersen_hash::pedersen_hash::<E, _>(
+        Personalization::NoteCommitment,
+        bits.into_iter(),
+        params,
+    );
+    let (x, _y) = point.into_xy();
+
+    fr_to_bytes_be::<E>(&x)
+}
+
+///
+/// Decomposes a scalar's native value into its big-endian bit vector, using
+/// the scalar's declared bit length (falling back to an all-zero witness if
+/// the value is missing, e.g. during constraint-count-only synthesis).
+///
+fn scalar_to_bits_be<E: IEngine>(value: &Scalar<E>) -> Vec<bool> {
+    let bitlength = value.get_type().bitlength();
+    let bigint = value.to_bigint().unwrap_or_default();
+
+    (0..bitlength)
+        .rev()
+        .map(|i| bigint.bit(i as u64))
+        .collect()
+}
+
+fn fr_to_bytes_be<E: JubjubEngine>(value: &E::Fr) -> Vec<u8> {
+    use franklin_crypto::bellman::pairing::ff::PrimeField;
+    use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+
+    let mut bytes = vec![0u8; (E::Fr::NUM_BITS as usize + 7) / zinc_const::BITLENGTH_BYTE];
+    value
+        .into_repr()
+        .write_be(&mut bytes[..])
+        .expect("fixed-size buffer matches the field's bit length");
+    bytes
+}
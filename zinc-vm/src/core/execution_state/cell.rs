@@ -0,0 +1,30 @@
+//!
+//! A single evaluation stack slot.
+//!
+
+use crate::error::RuntimeError;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+/// A single slot on the evaluation stack. Currently every slot holds a
+/// circuit value, but kept as an enum (rather than a bare `Scalar<E>`) so
+/// control-flow bookkeeping (loop bounds, return addresses) can grow into it
+/// without changing `EvaluationStack`'s element type.
+#[derive(Debug, Clone)]
+pub enum Cell<E: IEngine> {
+    Value(Scalar<E>),
+}
+
+impl<E: IEngine> Cell<E> {
+    pub fn try_into_value(self) -> Result<Scalar<E>, RuntimeError> {
+        match self {
+            Self::Value(value) => Ok(value),
+        }
+    }
+}
+
+impl<E: IEngine> From<Scalar<E>> for Cell<E> {
+    fn from(value: Scalar<E>) -> Self {
+        Self::Value(value)
+    }
+}
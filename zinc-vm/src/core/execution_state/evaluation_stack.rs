@@ -0,0 +1,134 @@
+//!
+//! The evaluation stack.
+//!
+//! Following the value-stack design `wasmi` uses, values live in a single
+//! pre-allocated backing `Vec<Cell<E>>` addressed by an explicit
+//! top-of-stack index (`len`) rather than growing and shrinking one
+//! `Vec::push`/`Vec::pop` call at a time: popping only decrements `len`, it
+//! never truncates the backing vector, so the slots above `len` stay live
+//! memory that a following push overwrites in place instead of
+//! reallocating. Frame-local addressing is cached as a `frame_base` index,
+//! so reading a local is one addition instead of a walk from the bottom of
+//! the stack.
+//!
+
+use crate::core::execution_state::cell::Cell;
+use crate::error::RuntimeError;
+use crate::IEngine;
+
+/// Reserved once up front; a deeply recursive contract grows the backing
+/// `Vec` like any other, but the common case never reallocates.
+const DEFAULT_CAPACITY: usize = 256;
+
+pub struct EvaluationStack<E: IEngine> {
+    cells: Vec<Cell<E>>,
+    len: usize,
+    frame_base: usize,
+}
+
+impl<E: IEngine> EvaluationStack<E> {
+    pub fn new() -> Self {
+        Self {
+            cells: Vec::with_capacity(DEFAULT_CAPACITY),
+            len: 0,
+            frame_base: 0,
+        }
+    }
+
+    /// Reserves room for `additional` more cells without growing once
+    /// values start being pushed; call with a function's computed
+    /// local/temporary count right before entering its frame.
+    pub fn reserve(&mut self, additional: usize) {
+        self.cells.reserve(additional);
+    }
+
+    pub fn push(&mut self, cell: Cell<E>) -> Result<(), RuntimeError> {
+        self.write(self.len, cell);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Pushes every cell of a frame's locals in one `extend` rather than one
+    /// `push` call per local.
+    pub fn push_frame<I: IntoIterator<Item = Cell<E>>>(&mut self, cells: I) {
+        for cell in cells {
+            self.write(self.len, cell);
+            self.len += 1;
+        }
+    }
+
+    pub fn pop(&mut self) -> Result<Cell<E>, RuntimeError> {
+        if self.len == 0 {
+            return Err(RuntimeError::StackUnderflow);
+        }
+        self.len -= 1;
+        Ok(self.cells[self.len].clone())
+    }
+
+    /// Pops `count` cells at once and returns them as a slice view, in push
+    /// order, over the still-live backing storage above the new top of
+    /// stack — avoiding the per-element `Vec::with_capacity` + `reverse`
+    /// pattern of popping one cell at a time into a freshly built `Vec`.
+    pub fn pop_n(&mut self, count: usize) -> Result<&[Cell<E>], RuntimeError> {
+        if count > self.len {
+            return Err(RuntimeError::StackUnderflow);
+        }
+        self.len -= count;
+        Ok(&self.cells[self.len..self.len + count])
+    }
+
+    /// Reads the cell `offset` slots above the current frame's base without
+    /// removing it.
+    pub fn peek(&self, offset: usize) -> Result<&Cell<E>, RuntimeError> {
+        let index = self.frame_base + offset;
+        if index >= self.len {
+            return Err(RuntimeError::StackUnderflow);
+        }
+        Ok(&self.cells[index])
+    }
+
+    /// Overwrites the cell `offset` slots above the current frame's base.
+    pub fn set(&mut self, offset: usize, cell: Cell<E>) -> Result<(), RuntimeError> {
+        let index = self.frame_base + offset;
+        if index >= self.len {
+            return Err(RuntimeError::StackUnderflow);
+        }
+        self.cells[index] = cell;
+        Ok(())
+    }
+
+    /// Rebases frame-local addressing onto the current top of stack,
+    /// returning the previous base so the caller can restore it when the
+    /// frame returns.
+    pub fn enter_frame(&mut self) -> usize {
+        let previous_base = self.frame_base;
+        self.frame_base = self.len;
+        previous_base
+    }
+
+    pub fn leave_frame(&mut self, previous_base: usize) {
+        self.frame_base = previous_base;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn write(&mut self, index: usize, cell: Cell<E>) {
+        if index < self.cells.len() {
+            self.cells[index] = cell;
+        } else {
+            self.cells.push(cell);
+        }
+    }
+}
+
+impl<E: IEngine> Default for EvaluationStack<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
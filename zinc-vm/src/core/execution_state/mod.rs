@@ -0,0 +1,44 @@
+//!
+//! The contract/function execution state.
+//!
+
+pub mod cell;
+pub mod evaluation_stack;
+
+use self::evaluation_stack::EvaluationStack;
+use crate::core::trap::{StepBudget, Trap};
+use crate::IEngine;
+
+pub struct ExecutionState<E: IEngine> {
+    pub evaluation_stack: EvaluationStack<E>,
+    step_budget: StepBudget,
+}
+
+impl<E: IEngine> ExecutionState<E> {
+    pub fn new() -> Self {
+        Self {
+            evaluation_stack: EvaluationStack::new(),
+            step_budget: StepBudget::default(),
+        }
+    }
+
+    pub fn with_step_budget(max_steps: usize) -> Self {
+        Self {
+            evaluation_stack: EvaluationStack::new(),
+            step_budget: StepBudget::new(max_steps),
+        }
+    }
+
+    /// Accounts for one more executed instruction at `offset` against this
+    /// execution's step budget, returning the `Trap` that should abort
+    /// execution once the budget runs out.
+    pub fn tick(&mut self, offset: usize) -> Result<(), Trap> {
+        self.step_budget.tick(offset)
+    }
+}
+
+impl<E: IEngine> Default for ExecutionState<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
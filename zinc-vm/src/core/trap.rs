@@ -0,0 +1,151 @@
+//!
+//! The execution trap subsystem: a bounded step counter plus the reasons the
+//! executor can stop early other than running out of bytecode.
+//!
+
+use std::fmt;
+
+///
+/// The reason a `Trap` fired.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrapCode {
+    /// The step budget passed to the executor was exhausted before the
+    /// program finished.
+    StepLimitExceeded,
+    /// An `Assert` instruction evaluated its condition to `false`, carrying
+    /// the assertion's own message if it had one.
+    AssertionFailed(Option<String>),
+    /// An `Exit` instruction fired with the given exit code.
+    Exited(i32),
+}
+
+impl fmt::Display for TrapCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StepLimitExceeded => write!(f, "step limit exceeded"),
+            Self::AssertionFailed(Some(message)) => write!(f, "assertion failed: {}", message),
+            Self::AssertionFailed(None) => write!(f, "assertion failed"),
+            Self::Exited(code) => write!(f, "exited with code {}", code),
+        }
+    }
+}
+
+///
+/// A halted execution, distinct from `MalformedBytecode`: the program itself
+/// was well-formed, but the executor chose to stop it.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trap {
+    /// The reason execution stopped.
+    pub code: TrapCode,
+    /// The byte offset of the instruction that triggered the trap.
+    pub offset: usize,
+}
+
+impl Trap {
+    pub fn new(code: TrapCode, offset: usize) -> Self {
+        Self { code, offset }
+    }
+}
+
+impl fmt::Display for Trap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "trap at offset {}: {}", self.offset, self.code)
+    }
+}
+
+///
+/// A configurable maximum-steps counter, decremented once per executed
+/// instruction by the dispatch loop.
+///
+/// Borrowed from lightweight bytecode VMs so that untrusted or buggy
+/// programs (e.g. one whose `LoopBegin`/`LoopEnd` pair never terminates)
+/// cannot run the executor forever: construct with the desired ceiling and
+/// call `tick` for every instruction the dispatch loop is about to execute,
+/// propagating the `Trap` it returns instead of continuing.
+///
+/// Only `Contains` (`instructions/call_library/collections_mtreemap/contains.rs`)
+/// actually calls `tick` right now. Wiring every opcode through it means
+/// calling `tick` from the top-level bytecode dispatch loop, but that loop
+/// (and the `IVirtualMachine` trait `Assert`'s and the `call_std` natives'
+/// `execute`/`call` take instead of `&mut ExecutionState`) isn't part of
+/// this checkout, so there's no dispatch site here to add the call to.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct StepBudget {
+    remaining: usize,
+}
+
+impl StepBudget {
+    /// No limit at all: `tick` never traps.
+    pub const UNLIMITED: usize = usize::MAX;
+
+    pub fn new(max_steps: usize) -> Self {
+        Self {
+            remaining: max_steps,
+        }
+    }
+
+    ///
+    /// Accounts for one more executed instruction at `offset`, returning a
+    /// `Trap` once the budget is exhausted.
+    ///
+    pub fn tick(&mut self, offset: usize) -> Result<(), Trap> {
+        if self.remaining == 0 {
+            return Err(Trap::new(TrapCode::StepLimitExceeded, offset));
+        }
+
+        self.remaining -= 1;
+        Ok(())
+    }
+}
+
+impl Default for StepBudget {
+    fn default() -> Self {
+        Self::new(Self::UNLIMITED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exhausts_after_max_steps() {
+        let mut budget = StepBudget::new(2);
+
+        assert_eq!(budget.tick(0), Ok(()));
+        assert_eq!(budget.tick(1), Ok(()));
+        assert_eq!(
+            budget.tick(2),
+            Err(Trap::new(TrapCode::StepLimitExceeded, 2))
+        );
+    }
+
+    #[test]
+    fn test_unlimited_never_traps() {
+        let mut budget = StepBudget::default();
+
+        for offset in 0..10_000 {
+            assert_eq!(budget.tick(offset), Ok(()));
+        }
+    }
+
+    #[test]
+    fn assertion_failed_carries_its_message_through_display() {
+        let trap = Trap::new(TrapCode::AssertionFailed(Some("must be nonzero".into())), 7);
+
+        assert_eq!(
+            trap.to_string(),
+            "trap at offset 7: assertion failed: must be nonzero"
+        );
+    }
+
+    #[test]
+    fn assertion_failed_without_a_message_still_displays() {
+        let trap = Trap::new(TrapCode::AssertionFailed(None), 3);
+
+        assert_eq!(trap.to_string(), "trap at offset 3: assertion failed");
+    }
+}
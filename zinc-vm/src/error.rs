@@ -0,0 +1,86 @@
+//!
+//! The VM runtime error.
+//!
+
+use std::fmt;
+
+use franklin_crypto::bellman::SynthesisError;
+
+use crate::core::trap::Trap;
+
+///
+/// An error that can occur while executing a compiled Zinc program.
+///
+#[derive(Debug)]
+pub enum RuntimeError {
+    /// A constraint system operation failed (e.g. an unsatisfiable assignment).
+    SynthesisError(SynthesisError),
+    /// A `dbg!` format string could not be rendered against its arguments.
+    InvalidDebugFormat(String),
+    /// A value did not have the scalar type an operation expected.
+    TypeError {
+        /// The type the operation required.
+        expected: String,
+        /// The type the value actually had.
+        found: String,
+    },
+    /// Execution was halted by the step budget or another non-bytecode reason.
+    Trap(Trap),
+    /// A contract-only native call was made outside contract execution.
+    OnlyForContracts,
+    /// The evaluation stack did not have enough values for the operation.
+    StackUnderflow,
+    /// A storage leaf held a value shape the calling native function can't use.
+    InvalidStorageValue,
+    /// The bytecode called a native function with arguments it can't accept.
+    MalformedBytecode(MalformedBytecode),
+}
+
+///
+/// Why a native (non-bytecode) function call in the program couldn't be
+/// executed, as opposed to a `RuntimeError` arising from the witness the
+/// prover supplied: the bytecode itself is at fault, not this particular run.
+///
+#[derive(Debug)]
+pub enum MalformedBytecode {
+    /// A native function was called with arguments its gadget can't accept
+    /// (e.g. the wrong count, or a length outside what the circuit supports).
+    InvalidArguments(String),
+}
+
+impl fmt::Display for MalformedBytecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidArguments(message) => write!(f, "invalid arguments: {}", message),
+        }
+    }
+}
+
+impl From<SynthesisError> for RuntimeError {
+    fn from(inner: SynthesisError) -> Self {
+        Self::SynthesisError(inner)
+    }
+}
+
+impl From<MalformedBytecode> for RuntimeError {
+    fn from(inner: MalformedBytecode) -> Self {
+        Self::MalformedBytecode(inner)
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SynthesisError(inner) => write!(f, "synthesis error: {}", inner),
+            Self::InvalidDebugFormat(message) => write!(f, "invalid debug format: {}", message),
+            Self::TypeError { expected, found } => {
+                write!(f, "type error: expected {}, found {}", expected, found)
+            }
+            Self::Trap(trap) => write!(f, "{}", trap),
+            Self::OnlyForContracts => write!(f, "this call is only available for contracts"),
+            Self::StackUnderflow => write!(f, "stack underflow"),
+            Self::InvalidStorageValue => write!(f, "invalid storage value"),
+            Self::MalformedBytecode(inner) => write!(f, "malformed bytecode: {}", inner),
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//!
+//! The integer bitwise and shift gadgets.
+//!
+//! `and` in the adjacent module only accepts `ScalarType::Boolean` and costs a single
+//! multiplication constraint, but the bitwise parser also expects AND/OR/XOR/NOT and the
+//! shifts to work on integer operands. Those need one boolean bit per operand bit instead
+//! of a single constraint, so this module decomposes each integer `Scalar<E>` into its
+//! `bitlength` allocated bits (each constrained boolean via `b*(b-1)=0`), checks the
+//! recomposition `sum(b_i * 2^i) == value`, applies the per-bit gate, and recomposes the
+//! result bits into a new `Scalar` of the same integer type.
+//!
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+
+use crate::auto_const;
+use crate::error::RuntimeError;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+/// The per-bit gate a binary bitwise op applies to each pair of decomposed bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Gate {
+    And,
+    Or,
+    Xor,
+}
+
+pub fn and<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(
+        cs: CS,
+        left: &Scalar<E>,
+        right: &Scalar<E>,
+    ) -> Result<Scalar<E>, RuntimeError>
+    where
+        E: IEngine,
+        CS: ConstraintSystem<E>,
+    {
+        binary(cs, left, right, Gate::And)
+    }
+
+    auto_const!(inner, cs, left, right)
+}
+
+pub fn or<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(
+        cs: CS,
+        left: &Scalar<E>,
+        right: &Scalar<E>,
+    ) -> Result<Scalar<E>, RuntimeError>
+    where
+        E: IEngine,
+        CS: ConstraintSystem<E>,
+    {
+        binary(cs, left, right, Gate::Or)
+    }
+
+    auto_const!(inner, cs, left, right)
+}
+
+pub fn xor<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(
+        cs: CS,
+        left: &Scalar<E>,
+        right: &Scalar<E>,
+    ) -> Result<Scalar<E>, RuntimeError>
+    where
+        E: IEngine,
+        CS: ConstraintSystem<E>,
+    {
+        binary(cs, left, right, Gate::Xor)
+    }
+
+    auto_const!(inner, cs, left, right)
+}
+
+///
+/// `NOT` cannot go through `auto_const!`, which is wired for two-`Scalar` operands; a
+/// constant operand still short-circuits via `Scalar::is_constant` below.
+///
+pub fn not<E, CS>(mut cs: CS, operand: &Scalar<E>) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let bitlength = integer_bitlength(operand.get_type())?;
+    let bits = decompose(cs.namespace(|| "decompose"), operand, bitlength)?;
+
+    let mut result_bits = Vec::with_capacity(bitlength);
+    for (index, bit) in bits.iter().enumerate() {
+        result_bits.push(not_bit(cs.namespace(|| format!("bit {}", index)), bit)?);
+    }
+
+    recompose(cs.namespace(|| "recompose"), &result_bits, operand.get_type())
+}
+
+///
+/// Shifts `operand` left by the compile-time `amount`, filling the vacated low bits with
+/// zero. The shift amount is static, so this is a reindexing of the bit vector rather than
+/// a new set of constraints per bit.
+///
+pub fn shift_left<E, CS>(
+    mut cs: CS,
+    operand: &Scalar<E>,
+    amount: usize,
+) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let bitlength = integer_bitlength(operand.get_type())?;
+    let bits = decompose(cs.namespace(|| "decompose"), operand, bitlength)?;
+
+    let shifted = shift_bits(cs.namespace(|| "zero"), &bits, amount, true)?;
+
+    recompose(cs.namespace(|| "recompose"), &shifted, operand.get_type())
+}
+
+///
+/// Shifts `operand` right by the compile-time `amount`, filling the vacated high bits with
+/// zero (a logical, not arithmetic, shift).
+///
+pub fn shift_right<E, CS>(
+    mut cs: CS,
+    operand: &Scalar<E>,
+    amount: usize,
+) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let bitlength = integer_bitlength(operand.get_type())?;
+    let bits = decompose(cs.namespace(|| "decompose"), operand, bitlength)?;
+
+    let shifted = shift_bits(cs.namespace(|| "zero"), &bits, amount, false)?;
+
+    recompose(cs.namespace(|| "recompose"), &shifted, operand.get_type())
+}
+
+fn binary<E, CS>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+    gate: Gate,
+) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let scalar_type = left.get_type();
+    let bitlength = integer_bitlength(scalar_type)?;
+
+    let left_bits = decompose(cs.namespace(|| "decompose left"), left, bitlength)?;
+    let right_bits = decompose(cs.namespace(|| "decompose right"), right, bitlength)?;
+
+    let mut result_bits = Vec::with_capacity(bitlength);
+    for (index, (a, b)) in left_bits.iter().zip(right_bits.iter()).enumerate() {
+        result_bits.push(gate_bit(cs.namespace(|| format!("bit {}", index)), a, b, gate)?);
+    }
+
+    recompose(cs.namespace(|| "recompose"), &result_bits, scalar_type)
+}
+
+///
+/// Returns the bit width `scalar_type` decomposes into, rejecting anything that is not an
+/// integer (booleans already have their own single-constraint `and`; fields have no fixed
+/// width to decompose into).
+///
+fn integer_bitlength(scalar_type: ScalarType) -> Result<usize, RuntimeError> {
+    match scalar_type {
+        ScalarType::Integer(IntegerType { bitlength, .. }) => Ok(bitlength),
+        scalar_type => Err(RuntimeError::TypeError {
+            expected: "integer type".into(),
+            found: format!("{:?}", scalar_type),
+        }),
+    }
+}
+
+///
+/// Allocates `bitlength` boolean bits for `scalar`, least-significant bit first, each
+/// constrained by `b*(b-1)=0`, and checks the recomposition `sum(b_i * 2^i) == scalar`.
+///
+fn decompose<E, CS>(
+    mut cs: CS,
+    scalar: &Scalar<E>,
+    bitlength: usize,
+) -> Result<Vec<AllocatedNum<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut bits = Vec::with_capacity(bitlength);
+
+    for index in 0..bitlength {
+        let bit = AllocatedNum::alloc(cs.namespace(|| format!("bit {}", index)), || {
+            let repr = scalar.grab_value()?.into_repr();
+            let limb = repr.as_ref()[index / 64];
+            let is_set = (limb >> (index % 64)) & 1 == 1;
+            Ok(if is_set { E::Fr::one() } else { E::Fr::zero() })
+        })?;
+
+        cs.enforce(
+            || format!("bit {} is boolean", index),
+            |lc| lc + bit.get_variable(),
+            |lc| lc + CS::one() - bit.get_variable(),
+            |lc| lc,
+        );
+
+        bits.push(bit);
+    }
+
+    enforce_recomposition(cs.namespace(|| "decomposition"), &bits, scalar)?;
+
+    Ok(bits)
+}
+
+///
+/// Recomposes `bits` (least-significant first) into a new `Scalar` of `scalar_type`,
+/// enforcing `sum(b_i * 2^i) == value`.
+///
+fn recompose<E, CS>(
+    mut cs: CS,
+    bits: &[AllocatedNum<E>],
+    scalar_type: ScalarType,
+) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let value = AllocatedNum::alloc(cs.namespace(|| "value"), || {
+        let mut result = E::Fr::zero();
+        let mut power = E::Fr::one();
+        for bit in bits {
+            let mut term = bit.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+            term.mul_assign(&power);
+            result.add_assign(&term);
+            power.double();
+        }
+        Ok(result)
+    })?;
+
+    let scalar = Scalar::new_unchecked_variable(value.get_value(), value.get_variable(), scalar_type);
+    enforce_recomposition(cs.namespace(|| "recomposition"), bits, &scalar)?;
+
+    Ok(scalar)
+}
+
+///
+/// Enforces `sum(bits[i] * 2^i) == scalar` as a single linear equality constraint, shared
+/// by both decomposition (check) and recomposition (construction).
+///
+fn enforce_recomposition<E, CS>(
+    mut cs: CS,
+    bits: &[AllocatedNum<E>],
+    scalar: &Scalar<E>,
+) -> Result<(), RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    cs.enforce(
+        || "bits recompose to value",
+        |lc| {
+            let mut lc = lc;
+            let mut power = E::Fr::one();
+            for bit in bits {
+                lc = lc + (power, bit.get_variable());
+                power.double();
+            }
+            lc
+        },
+        |lc| lc + CS::one(),
+        |lc| lc + &scalar.to_linear_combination::<CS>(),
+    );
+
+    Ok(())
+}
+
+///
+/// Applies `gate` to one pair of decomposed bits, allocating and constraining the result
+/// bit. `AND` is the bare product; `OR`/`XOR` are affine combinations of the operands and
+/// their product, each enforced with one extra linear constraint.
+///
+fn gate_bit<E, CS>(
+    mut cs: CS,
+    a: &AllocatedNum<E>,
+    b: &AllocatedNum<E>,
+    gate: Gate,
+) -> Result<AllocatedNum<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let product = AllocatedNum::alloc(cs.namespace(|| "product"), || {
+        let mut product = a.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        product.mul_assign(&b.get_value().ok_or(SynthesisError::AssignmentMissing)?);
+        Ok(product)
+    })?;
+
+    cs.enforce(
+        || "product",
+        |lc| lc + a.get_variable(),
+        |lc| lc + b.get_variable(),
+        |lc| lc + product.get_variable(),
+    );
+
+    if gate == Gate::And {
+        return Ok(product);
+    }
+
+    let result = AllocatedNum::alloc(cs.namespace(|| "result"), || {
+        let a = a.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        let b = b.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        let product = product.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+
+        let mut sum = a;
+        sum.add_assign(&b);
+
+        let mut subtrahend = product;
+        if gate == Gate::Xor {
+            subtrahend.double();
+        }
+        sum.sub_assign(&subtrahend);
+
+        Ok(sum)
+    })?;
+
+    let product_factor = if gate == Gate::Xor {
+        let mut two = E::Fr::one();
+        two.double();
+        two
+    } else {
+        E::Fr::one()
+    };
+
+    cs.enforce(
+        || "result",
+        |lc| lc + result.get_variable(),
+        |lc| lc + CS::one(),
+        |lc| lc + a.get_variable() + b.get_variable() - (product_factor, product.get_variable()),
+    );
+
+    Ok(result)
+}
+
+///
+/// `result = 1 - a`, enforced as a single linear constraint.
+///
+fn not_bit<E, CS>(mut cs: CS, a: &AllocatedNum<E>) -> Result<AllocatedNum<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let result = AllocatedNum::alloc(cs.namespace(|| "result"), || {
+        let mut value = E::Fr::one();
+        value.sub_assign(&a.get_value().ok_or(SynthesisError::AssignmentMissing)?);
+        Ok(value)
+    })?;
+
+    cs.enforce(
+        || "result",
+        |lc| lc + result.get_variable(),
+        |lc| lc + CS::one(),
+        |lc| lc + CS::one() - a.get_variable(),
+    );
+
+    Ok(result)
+}
+
+///
+/// Reindexes `bits` (least-significant first) by `amount` positions, zero-filling the
+/// vacated end. `towards_msb` is `true` for a left shift and `false` for a right shift;
+/// since the shift amount is a compile-time constant, this needs no extra constraints of
+/// its own beyond the zero-fill allocations.
+///
+fn shift_bits<E, CS>(
+    mut cs: CS,
+    bits: &[AllocatedNum<E>],
+    amount: usize,
+    towards_msb: bool,
+) -> Result<Vec<AllocatedNum<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let bitlength = bits.len();
+    let mut result = Vec::with_capacity(bitlength);
+
+    for index in 0..bitlength {
+        let source = if towards_msb {
+            index.checked_sub(amount)
+        } else {
+            index
+                .checked_add(amount)
+                .filter(|&shifted| shifted < bitlength)
+        };
+
+        let bit = match source {
+            Some(source) => bits[source].clone(),
+            None => {
+                let zero_bit =
+                    AllocatedNum::alloc(cs.namespace(|| format!("zero {}", index)), || {
+                        Ok(E::Fr::zero())
+                    })?;
+                cs.enforce(
+                    || format!("zero {} is zero", index),
+                    |lc| lc + zero_bit.get_variable(),
+                    |lc| lc + CS::one(),
+                    |lc| lc,
+                );
+                zero_bit
+            }
+        };
+
+        result.push(bit);
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,87 @@
+//!
+//! Equality comparison between arbitrary (non-boolean) field-typed scalars.
+//!
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use zinc_build::ScalarType;
+
+use crate::error::RuntimeError;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// Proves `left == right` and returns the boolean result, rather than
+/// computing it out of circuit the way a plain `==` on the witnessed values
+/// would: a prover who only has to satisfy the constraints below cannot pick
+/// `result = 1` unless `left` and `right` are actually equal.
+///
+/// The standard two-constraint trick: allocate `inverse` as `(left -
+/// right)^-1` when the operands differ (any value satisfies the system when
+/// they don't), then bind `result` with
+///   `(left - right) * inverse == 1 - result`   (forces `result = 0` whenever
+///                                                `left != right`, since only
+///                                                a genuine inverse exists)
+///   `(left - right) * result == 0`             (forces `result = 0` whenever
+///                                                `left != right`... and
+///                                                leaves `result = 1` as the
+///                                                only value both satisfy
+///                                                when `left == right`)
+///
+pub fn equals<E, CS>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let difference = match (left.grab_value(), right.grab_value()) {
+        (Ok(left_value), Ok(right_value)) => {
+            let mut difference = left_value;
+            difference.sub_assign(&right_value);
+            Some(difference)
+        }
+        _ => None,
+    };
+
+    let inverse = AllocatedNum::alloc(cs.namespace(|| "inverse"), || {
+        let difference = difference.ok_or(franklin_crypto::bellman::SynthesisError::AssignmentMissing)?;
+        Ok(difference.inverse().unwrap_or_else(E::Fr::zero))
+    })?;
+
+    let result = AllocatedNum::alloc(cs.namespace(|| "result"), || {
+        let difference = difference.ok_or(franklin_crypto::bellman::SynthesisError::AssignmentMissing)?;
+        Ok(if difference.is_zero() {
+            E::Fr::one()
+        } else {
+            E::Fr::zero()
+        })
+    })?;
+
+    let left_lc = left.to_linear_combination::<CS>();
+    let right_lc = right.to_linear_combination::<CS>();
+
+    cs.enforce(
+        || "a non-zero difference has an inverse, so result must be 0",
+        |lc| lc + &left_lc - &right_lc,
+        |lc| lc + inverse.get_variable(),
+        |lc| lc + CS::one() - result.get_variable(),
+    );
+
+    cs.enforce(
+        || "result can only be 1 when the difference is 0",
+        |lc| lc + &left_lc - &right_lc,
+        |lc| lc + result.get_variable(),
+        |lc| lc,
+    );
+
+    Ok(Scalar::new_unchecked_variable(
+        result.get_value(),
+        result.get_variable(),
+        ScalarType::Boolean,
+    ))
+}
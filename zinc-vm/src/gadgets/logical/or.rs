@@ -0,0 +1,66 @@
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use zinc_build::ScalarType;
+
+use crate::auto_const;
+use crate::error::RuntimeError;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::scalar::expectation::ITypeExpectation;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+pub fn or<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(
+        mut cs: CS,
+        left: &Scalar<E>,
+        right: &Scalar<E>,
+    ) -> Result<Scalar<E>, RuntimeError>
+    where
+        E: IEngine,
+        CS: ConstraintSystem<E>,
+    {
+        left.get_type().assert_type(ScalarType::Boolean)?;
+        right.get_type().assert_type(ScalarType::Boolean)?;
+
+        // For booleans, `l OR r = l + r - l*r`: constrain the product once,
+        // then hand back that relation's other side as the result, the same
+        // one-constraint shape `and` uses for `l AND r = l*r`.
+        let num = AllocatedNum::alloc(cs.namespace(|| "value"), || {
+            let left_value = left.grab_value()?;
+            let right_value = right.grab_value()?;
+
+            let mut product = left_value;
+            product.mul_assign(&right_value);
+
+            let mut sum = left_value;
+            sum.add_assign(&right_value);
+            sum.sub_assign(&product);
+
+            Ok(sum)
+        })?;
+
+        cs.enforce(
+            || "equality",
+            |lc| lc + &left.to_linear_combination::<CS>(),
+            |lc| lc + &right.to_linear_combination::<CS>(),
+            |lc| {
+                lc + &left.to_linear_combination::<CS>() + &right.to_linear_combination::<CS>()
+                    - num.get_variable()
+            },
+        );
+
+        Ok(Scalar::new_unchecked_variable(
+            num.get_value(),
+            num.get_variable(),
+            ScalarType::Boolean,
+        ))
+    }
+
+    auto_const!(inner, cs, left, right)
+}
@@ -0,0 +1,57 @@
+//!
+//! The `std::crypto::blake2s` gadget.
+//!
+//! Thin wrapper around `franklin_crypto`'s own Blake2s circuit (the same one
+//! backing Sapling note commitments), the same way `schnorr.rs` delegates to
+//! `franklin_crypto::circuit::sha256::sha256` rather than reimplementing
+//! SHA-256 bit by bit. Unkeyed, 32-byte digest, empty personalization, since
+//! `std::crypto::blake2s` has no notion of a domain separator.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::blake2s::blake2s;
+
+use crate::error::RuntimeError;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+/// `franklin_crypto`'s Blake2s circuit takes an 8-byte personalization
+/// string; `std::crypto::blake2s` has none, so it is left all-zero.
+const PERSONALIZATION: [u8; 8] = [0; 8];
+
+///
+/// Hashes `preimage` (a big-endian-bit-ordered, byte-multiple boolean array,
+/// as enforced by the semantic layer) with Blake2s, returning the 256-bit
+/// digest in the same big-endian bit order.
+///
+pub fn blake2s_gadget<E, CS>(
+    mut cs: CS,
+    preimage: &[Scalar<E>],
+) -> Result<Vec<Scalar<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let preimage_bits = preimage
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| bit.to_boolean(cs.namespace(|| format!("preimage bit {}", i))))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(RuntimeError::SynthesisError)?;
+
+    let digest_bits = blake2s(
+        cs.namespace(|| "blake2s"),
+        &preimage_bits,
+        &PERSONALIZATION,
+    )
+    .map_err(RuntimeError::SynthesisError)?;
+
+    digest_bits
+        .into_iter()
+        .enumerate()
+        .map(|(i, bit)| {
+            Scalar::from_boolean(cs.namespace(|| format!("digest bit {}", i)), bit)
+                .map_err(RuntimeError::SynthesisError)
+        })
+        .collect()
+}
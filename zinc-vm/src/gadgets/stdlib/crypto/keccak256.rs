@@ -0,0 +1,356 @@
+//!
+//! The `std::crypto::keccak256` gadget.
+//!
+//! Implements the Keccak-f[1600] sponge construction with 1088-bit rate and
+//! 512-bit capacity over a 5x5 array of 64-bit lanes, using the original
+//! Keccak multi-rate `pad10*1` padding (domain separator `0x01`) rather than
+//! the later NIST SHA-3 `0x06` separator, matching the digest Ethereum calls
+//! `keccak256`. Each lane bit is a `Boolean`, so `theta`/`chi` (XOR/AND over
+//! lanes) and `rho`/`pi` (compile-time bit rotation and lane permutation,
+//! free of constraints) are expressed directly in terms of the boolean
+//! gadgets `franklin_crypto` already provides.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+use crate::error::RuntimeError;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+const LANE_BITS: usize = 64;
+const LANES_PER_SIDE: usize = 5;
+const RATE_BITS: usize = 1088;
+const ROUNDS: usize = 24;
+
+const ROUND_CONSTANTS: [u64; ROUNDS] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808a,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b,
+    0x8000_0000_0000_008b,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800a,
+    0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// `r[x][y]`: the per-lane left-rotation amount applied by `rho`.
+const ROTATION_OFFSETS: [[u32; LANES_PER_SIDE]; LANES_PER_SIDE] = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+];
+
+/// A 5x5 array of 64-bit lanes, each bit least-significant-first, as laid
+/// out by the Keccak specification.
+type State = Vec<Vec<Vec<Boolean>>>;
+
+///
+/// Hashes `preimage` (a big-endian-bit-ordered, byte-multiple boolean array,
+/// as enforced by the semantic layer) with Keccak-256, returning the
+/// 256-bit digest in the same big-endian bit order.
+///
+pub fn keccak256<E, CS>(mut cs: CS, preimage: &[Scalar<E>]) -> Result<Vec<Scalar<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let preimage_bits = preimage
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| bit.to_boolean(cs.namespace(|| format!("preimage bit {}", i))))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(RuntimeError::SynthesisError)?;
+
+    let padded = pad(&preimage_bits);
+
+    let mut state = empty_state();
+    for (block_index, block) in padded.chunks(RATE_BITS).enumerate() {
+        state = absorb(
+            cs.namespace(|| format!("absorb block {}", block_index)),
+            state,
+            block,
+        )?;
+        state = permute(
+            cs.namespace(|| format!("keccak-f block {}", block_index)),
+            state,
+        )?;
+    }
+
+    let digest_bits = squeeze(&state, zinc_const::bitlength::BYTE * 32);
+
+    digest_bits
+        .into_iter()
+        .enumerate()
+        .map(|(i, bit)| {
+            Scalar::from_boolean(cs.namespace(|| format!("digest bit {}", i)), bit)
+                .map_err(RuntimeError::SynthesisError)
+        })
+        .collect()
+}
+
+/// Applies the multi-rate `pad10*1` padding used by the original Keccak (not
+/// NIST SHA-3): a single `1` bit, zero or more `0` bits, and a final `1` bit,
+/// bringing the length up to a multiple of `RATE_BITS`.
+fn pad(bits: &[Boolean]) -> Vec<Boolean> {
+    let mut padded = bits.to_vec();
+    padded.push(Boolean::constant(true));
+    while padded.len() % RATE_BITS != RATE_BITS - 1 {
+        padded.push(Boolean::constant(false));
+    }
+    padded.push(Boolean::constant(true));
+    padded
+}
+
+fn empty_state() -> State {
+    vec![vec![vec![Boolean::constant(false); LANE_BITS]; LANES_PER_SIDE]; LANES_PER_SIDE]
+}
+
+/// XORs one rate-sized block of the sponge's input into `state`'s first
+/// `RATE_BITS` bits, lane by lane, in column-major (x outer, y inner) order.
+fn absorb<E, CS>(mut cs: CS, mut state: State, block: &[Boolean]) -> Result<State, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    for (lane_index, lane_bits) in block.chunks(LANE_BITS).enumerate() {
+        let x = lane_index % LANES_PER_SIDE;
+        let y = lane_index / LANES_PER_SIDE;
+        state[x][y] = xor_lane(
+            cs.namespace(|| format!("lane [{},{}]", x, y)),
+            &state[x][y],
+            lane_bits,
+        )?;
+    }
+    Ok(state)
+}
+
+/// Reads out the first `bit_count` bits of the sponge's rate in the same
+/// column-major lane order `absorb` writes them in.
+fn squeeze(state: &State, bit_count: usize) -> Vec<Boolean> {
+    let mut out = Vec::with_capacity(bit_count);
+    'lanes: for y in 0..LANES_PER_SIDE {
+        for x in 0..LANES_PER_SIDE {
+            for bit in &state[x][y] {
+                if out.len() == bit_count {
+                    break 'lanes;
+                }
+                out.push(bit.clone());
+            }
+        }
+    }
+    out
+}
+
+/// Runs the `ROUNDS`-round Keccak-f[1600] permutation (theta, rho+pi, chi,
+/// iota) over `state`.
+fn permute<E, CS>(mut cs: CS, mut state: State) -> Result<State, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    for round in 0..ROUNDS {
+        state = round_function(cs.namespace(|| format!("round {}", round)), state, round)?;
+    }
+    Ok(state)
+}
+
+fn round_function<E, CS>(mut cs: CS, state: State, round: usize) -> Result<State, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    // theta: C[x] = A[x][0] ^ ... ^ A[x][4]; D[x] = C[x-1] ^ rotl(C[x+1], 1);
+    // A[x][y] ^= D[x].
+    let mut columns = Vec::with_capacity(LANES_PER_SIDE);
+    for x in 0..LANES_PER_SIDE {
+        let mut column = state[x][0].clone();
+        for y in 1..LANES_PER_SIDE {
+            column = xor_lane(
+                cs.namespace(|| format!("theta C[{}] ^= A[{},{}]", x, x, y)),
+                &column,
+                &state[x][y],
+            )?;
+        }
+        columns.push(column);
+    }
+
+    let mut theta = state.clone();
+    for x in 0..LANES_PER_SIDE {
+        let left = &columns[(x + LANES_PER_SIDE - 1) % LANES_PER_SIDE];
+        let right = rotate_left(&columns[(x + 1) % LANES_PER_SIDE], 1);
+        let d = xor_lane(cs.namespace(|| format!("theta D[{}]", x)), left, &right)?;
+        for y in 0..LANES_PER_SIDE {
+            theta[x][y] = xor_lane(
+                cs.namespace(|| format!("theta A[{},{}] ^= D[{}]", x, y, x)),
+                &theta[x][y],
+                &d,
+            )?;
+        }
+    }
+
+    // rho + pi: B[y][2x+3y mod 5] = rotl(A[x][y], r[x][y]).
+    let mut rho_pi = empty_state();
+    for x in 0..LANES_PER_SIDE {
+        for y in 0..LANES_PER_SIDE {
+            let rotated = rotate_left(&theta[x][y], ROTATION_OFFSETS[x][y] as usize);
+            let new_x = y;
+            let new_y = (2 * x + 3 * y) % LANES_PER_SIDE;
+            rho_pi[new_x][new_y] = rotated;
+        }
+    }
+
+    // chi: A[x][y] = B[x][y] ^ ((!B[x+1][y]) & B[x+2][y]).
+    let mut chi = empty_state();
+    for x in 0..LANES_PER_SIDE {
+        for y in 0..LANES_PER_SIDE {
+            let not_next = not_lane(&rho_pi[(x + 1) % LANES_PER_SIDE][y]);
+            let and_term = and_lane(
+                cs.namespace(|| format!("chi A[{},{}] term", x, y)),
+                &not_next,
+                &rho_pi[(x + 2) % LANES_PER_SIDE][y],
+            )?;
+            chi[x][y] = xor_lane(
+                cs.namespace(|| format!("chi A[{},{}]", x, y)),
+                &rho_pi[x][y],
+                &and_term,
+            )?;
+        }
+    }
+
+    // iota: A[0][0] ^= RC[round].
+    let rc_bits = u64_to_lane_bits(ROUND_CONSTANTS[round]);
+    chi[0][0] = xor_lane(cs.namespace(|| "iota"), &chi[0][0], &rc_bits)?;
+
+    Ok(chi)
+}
+
+fn u64_to_lane_bits(value: u64) -> Vec<Boolean> {
+    (0..LANE_BITS)
+        .map(|i| Boolean::constant((value >> i) & 1 == 1))
+        .collect()
+}
+
+/// Rotates `lane` left by `amount` positions. The amount is a compile-time
+/// constant, so this is a reindexing of the bit vector, not a new gadget.
+fn rotate_left(lane: &[Boolean], amount: usize) -> Vec<Boolean> {
+    let amount = amount % LANE_BITS;
+    let mut rotated = lane[LANE_BITS - amount..].to_vec();
+    rotated.extend_from_slice(&lane[..LANE_BITS - amount]);
+    rotated
+}
+
+fn xor_lane<E, CS>(mut cs: CS, a: &[Boolean], b: &[Boolean]) -> Result<Vec<Boolean>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    a.iter()
+        .zip(b.iter())
+        .enumerate()
+        .map(|(i, (a, b))| {
+            Boolean::xor(cs.namespace(|| format!("bit {}", i)), a, b).map_err(RuntimeError::SynthesisError)
+        })
+        .collect()
+}
+
+fn and_lane<E, CS>(mut cs: CS, a: &[Boolean], b: &[Boolean]) -> Result<Vec<Boolean>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    a.iter()
+        .zip(b.iter())
+        .enumerate()
+        .map(|(i, (a, b))| {
+            Boolean::and(cs.namespace(|| format!("bit {}", i)), a, b).map_err(RuntimeError::SynthesisError)
+        })
+        .collect()
+}
+
+fn not_lane(lane: &[Boolean]) -> Vec<Boolean> {
+    lane.iter().map(|bit| bit.not()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use pairing::bn256::Bn256;
+    use pairing::bn256::Fr;
+
+    use super::*;
+
+    /// Big-endian preimage bits for `bytes`, matching `keccak256`'s expected
+    /// input order (MSB of byte 0 first).
+    fn preimage_bits(bytes: &[u8]) -> Vec<Scalar<Bn256>> {
+        bytes
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .map(Scalar::new_constant_bool)
+            .collect()
+    }
+
+    /// Packs `keccak256`'s big-endian-bit digest output back into bytes.
+    fn digest_bytes(digest: &[Scalar<Bn256>]) -> Result<Vec<u8>, RuntimeError> {
+        digest
+            .chunks(8)
+            .map(|byte_bits| {
+                byte_bits.iter().try_fold(0u8, |byte, bit| {
+                    let is_one = bit.grab_value().map_err(RuntimeError::SynthesisError)? == Fr::one();
+                    Ok((byte << 1) | is_one as u8)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keccak256_of_abc_matches_known_answer() -> Result<(), RuntimeError> {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let preimage = preimage_bits(b"abc");
+        let digest = keccak256(cs.namespace(|| "keccak256"), &preimage)?;
+
+        assert!(cs.is_satisfied());
+        assert_eq!(
+            hex::encode(digest_bytes(&digest)?),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keccak256_of_empty_input_matches_known_answer() -> Result<(), RuntimeError> {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let preimage = preimage_bits(b"");
+        let digest = keccak256(cs.namespace(|| "keccak256"), &preimage)?;
+
+        assert!(cs.is_satisfied());
+        assert_eq!(
+            hex::encode(digest_bytes(&digest)?),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a47",
+        );
+
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@ use ff::PrimeField;
 use bellman::ConstraintSystem;
 use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
 use franklin_crypto::circuit::ecc::EdwardsPoint;
+use franklin_crypto::circuit::num::AllocatedNum;
+use franklin_crypto::circuit::sha256::sha256;
 use franklin_crypto::jubjub::{FixedGenerators, JubjubParams};
 
 use crate::{Engine, MalformedBytecode, Result};
@@ -122,6 +124,144 @@ pub fn verify_signature<E, CS>(
     Scalar::from_boolean(cs.namespace(|| "from_boolean"), is_verified)
 }
 
+/// `VerifySchnorrSignature` bounds the message to `E::Fs::CAPACITY` bits
+/// because it feeds the raw message bits straight into the EdDSA relation.
+/// This variant instead hashes the message down to a fixed-size challenge
+/// first, so contracts can verify signatures over arbitrarily large payloads
+/// (e.g. whole Merkle roots) instead of only short messages.
+pub struct VerifySchnorrSignatureHashed {
+    msg_len: usize,
+}
+
+impl VerifySchnorrSignatureHashed {
+    pub fn new(args_count: usize) -> Result<Self> {
+        if args_count < 6 {
+            return Err(MalformedBytecode::InvalidArguments(
+                "schnorr::verify_hashed needs at least 6 arguments".into(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            msg_len: args_count - 5,
+        })
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for VerifySchnorrSignatureHashed {
+    fn execute<CS>(&self, mut cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut message = Vec::new();
+        for _ in 0..self.msg_len {
+            let bit = stack.pop()?.value()?;
+            message.push(bit);
+        }
+
+        let pk_y = stack
+            .pop()?
+            .value()?
+            .to_expression::<CS>()
+            .into_number(cs.namespace(|| "to_number pk_y"))?;
+        let pk_x = stack
+            .pop()?
+            .value()?
+            .to_expression::<CS>()
+            .into_number(cs.namespace(|| "to_number pk_x"))?;
+        let s = stack
+            .pop()?
+            .value()?
+            .to_expression::<CS>()
+            .into_number(cs.namespace(|| "to_number s"))?;
+        let r_y = stack
+            .pop()?
+            .value()?
+            .to_expression::<CS>()
+            .into_number(cs.namespace(|| "to_number r_y"))?;
+        let r_x = stack
+            .pop()?
+            .value()?
+            .to_expression::<CS>()
+            .into_number(cs.namespace(|| "to_number r_x"))?;
+
+        let r = EdwardsPoint::interpret(cs.namespace(|| "r"), &r_x, &r_y, E::jubjub_params())?;
+        let pk = EdwardsPoint::interpret(cs.namespace(|| "pk"), &pk_x, &pk_y, E::jubjub_params())?;
+
+        let is_valid = verify_hashed_signature(
+            cs.namespace(|| "verify_hashed_signature"),
+            &message,
+            &r,
+            &pk,
+            &s,
+            E::jubjub_params(),
+        )?;
+
+        stack.push(is_valid.into())
+    }
+}
+
+///
+/// Verifies the standard EdDSA relation `s*B = R + c*A`, where the challenge
+/// `c = H(R_x || A_x || M)` is computed in-circuit via SHA-256 rather than
+/// bounding `M` to the scalar field's capacity.
+///
+/// Delegates the actual `s*B == R + c*A` check to
+/// `EddsaSignature::is_verified_raw_message_signature`, the same gadget
+/// `verify_signature` above calls, instead of re-deriving `s*B`, `c*A`, and
+/// the final point-equality check by hand: the only difference between the
+/// two verification paths is what bits feed the challenge (the raw message
+/// bits there vs. the SHA-256 digest here), so the shared gadget is handed
+/// the post-hash bits in place of the raw message bits.
+///
+pub fn verify_hashed_signature<E, CS>(
+    mut cs: CS,
+    message: &[Scalar<E>],
+    r: &EdwardsPoint<E>,
+    pk: &EdwardsPoint<E>,
+    s: &AllocatedNum<E>,
+    params: &E::Params,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let mut preimage = Vec::new();
+    preimage.extend(r.get_x().into_bits_le(cs.namespace(|| "r_x bits"))?);
+    preimage.extend(pk.get_x().into_bits_le(cs.namespace(|| "pk_x bits"))?);
+    for (i, bit) in message.iter().enumerate() {
+        preimage.push(bit.to_boolean(cs.namespace(|| format!("message bit {}", i)))?);
+    }
+
+    let challenge_bits = sha256(cs.namespace(|| "challenge"), &preimage)?;
+    let challenge_bits = challenge_bits[0..(E::Fs::CAPACITY as usize)].to_vec();
+
+    let signature = EddsaSignature {
+        r: r.clone(),
+        s: s.clone(),
+        pk: pk.clone(),
+    };
+
+    let public_generator = params
+        .generator(FixedGenerators::SpendingKeyGenerator)
+        .clone();
+    let generator = EdwardsPoint::witness(
+        cs.namespace(|| "allocate public generator"),
+        Some(public_generator),
+        params,
+    )?;
+
+    let is_valid = signature.is_verified_raw_message_signature(
+        cs.namespace(|| "is_verified_signature"),
+        params,
+        &challenge_bits,
+        generator,
+        (E::Fs::CAPACITY as usize + 7) / 8,
+    )?;
+
+    Scalar::from_boolean(cs.namespace(|| "from_boolean"), is_valid)
+}
+
 #[cfg(test)]
 mod tests {
     use ff::Field;
@@ -199,4 +339,79 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_verify_hashed_rejects_mismatched_signature() -> Result {
+        let params = AltJubjubBn256::new();
+        let p_g = jubjub::FixedGenerators::SpendingKeyGenerator;
+        let message = b"abc";
+
+        let message_bits = message
+            .iter()
+            .map(|byte| {
+                let mut bits = Vec::new();
+
+                for i in 0..8 {
+                    bits.push(byte & (1 << i) != 0);
+                }
+
+                bits
+            })
+            .flatten()
+            .map(|b| Scalar::new_constant_bool(b))
+            .collect::<Vec<_>>();
+
+        let mut rng = rand::thread_rng();
+        let key = eddsa::PrivateKey::<Bn256>(rng.gen());
+        let pub_key = eddsa::PublicKey::from_private(&key, p_g, &params);
+        // A signature over an unrelated message is unrelated to `message`'s
+        // hashed challenge, so it must not verify against it.
+        let seed = eddsa::Seed::random_seed(&mut rng, b"a different message");
+        let signature = key.sign_raw_message(
+            b"a different message",
+            &seed,
+            p_g,
+            &params,
+            <Bn256 as JubjubEngine>::Fs::CAPACITY as usize / 8,
+        );
+
+        let mut sigs_bytes = [0u8; 32];
+        signature
+            .s
+            .into_repr()
+            .write_le(&mut sigs_bytes[..])
+            .expect("get LE bytes of signature S");
+        let mut sigs_repr = <Fr as PrimeField>::Repr::from(0);
+        sigs_repr
+            .read_le(&sigs_bytes[..])
+            .expect("interpret S as field element representation");
+        let sigs_converted = Fr::from_repr(sigs_repr).unwrap();
+
+        let (r_x, r_y) = signature.r.into_xy();
+        let s = sigs_converted;
+        let (pk_x, pk_y) = pub_key.0.into_xy();
+
+        let mut stack = EvaluationStack::<Bn256>::new();
+        stack.push(Scalar::new_constant_fr(r_x, ScalarType::Field).into())?;
+        stack.push(Scalar::new_constant_fr(r_y, ScalarType::Field).into())?;
+        stack.push(Scalar::new_constant_fr(s, ScalarType::Field).into())?;
+        stack.push(Scalar::new_constant_fr(pk_x, ScalarType::Field).into())?;
+        stack.push(Scalar::new_constant_fr(pk_y, ScalarType::Field).into())?;
+        for bit in message_bits.into_iter().rev() {
+            stack.push(bit.into())?;
+        }
+
+        let mut cs = TestConstraintSystem::new();
+        VerifySchnorrSignatureHashed::new(5 + 8 * message.len())
+            .unwrap()
+            .execute(cs.namespace(|| "hashed signature check"), &mut stack)?;
+
+        let is_valid = stack.pop()?.value()?;
+
+        assert_eq!(is_valid.get_value(), Some(Fr::zero()), "must not verify");
+        assert!(cs.is_satisfied(), "unsatisfied");
+        assert_eq!(cs.which_is_unsatisfied(), None, "unconstrained");
+
+        Ok(())
+    }
 }
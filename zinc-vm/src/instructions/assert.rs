@@ -0,0 +1,60 @@
+//!
+//! The `Assert` instruction.
+//!
+
+use zinc_build::Assert;
+
+use crate::core::trap::Trap;
+use crate::core::trap::TrapCode;
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::RuntimeError;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for Assert {
+    fn execute(self, vm: &mut VM) -> Result<(), RuntimeError> {
+        let condition = vm.pop()?.try_into_value()?;
+
+        if let Some(condition) = condition.to_bigint() {
+            if condition.is_zero() {
+                // Routed through the same `Trap` type `ExecutionState::tick`
+                // uses (rather than a standalone `AssertionError`), so every
+                // reason the executor can halt a well-formed program --
+                // exhausting its step budget or failing an assertion -- is
+                // one `RuntimeError::Trap(_)` a caller can match uniformly.
+                return Err(RuntimeError::Trap(Trap::new(
+                    TrapCode::AssertionFailed(self.message),
+                    0,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::TestRunner;
+
+    #[test]
+    fn non_debug_assert_still_constrains_and_fails() {
+        TestRunner::new()
+            .push(zinc_build::Push::new_field(0.into()))
+            .push(zinc_build::Assert::new(Some("must be nonzero".into())))
+            .test::<u32>(&[])
+            .expect_err(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    #[test]
+    fn debug_assert_adds_zero_constraints_in_release() {
+        let with_debug_assert = zinc_build::optimize::strip_debug_instructions(vec![
+            zinc_build::Instruction::Assert(zinc_build::Assert::new_debug(None)),
+        ]);
+
+        assert!(
+            with_debug_assert.is_empty(),
+            "a release build must drop every debug assert before synthesis, \
+             so it contributes zero constraints"
+        );
+    }
+}
@@ -5,12 +5,17 @@
 use num::bigint::ToBigInt;
 
 use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::{AllocatedBit, Boolean};
 
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::ExecutionState;
 use crate::error::RuntimeError;
+use crate::gadgets::comparison;
+use crate::gadgets::contract::merkle_tree;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::logical::and;
+use crate::gadgets::logical::or;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
@@ -28,20 +33,28 @@ impl Contains {
 impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Contains {
     fn call<CS>(
         &self,
-        _cs: CS,
+        mut cs: CS,
         state: &mut ExecutionState<E>,
         storage: Option<&mut S>,
     ) -> Result<(), RuntimeError>
     where
         CS: ConstraintSystem<E>,
     {
+        // `ExecutionState::tick` enforces the caller's step budget; native
+        // library calls like this one are themselves one dispatched
+        // "instruction", so they must tick the same as any bytecode opcode
+        // the top-level VM dispatch loop executes.
+        state.tick(0).map_err(RuntimeError::Trap)?;
+
         let storage = storage.ok_or(RuntimeError::OnlyForContracts)?;
 
-        let mut input = Vec::with_capacity(self.input_size);
-        for _ in 0..self.input_size {
-            input.push(state.evaluation_stack.pop()?.try_into_value()?);
-        }
-        input.reverse();
+        let input = state
+            .evaluation_stack
+            .pop_n(self.input_size)?
+            .iter()
+            .cloned()
+            .map(Cell::try_into_value)
+            .collect::<Result<Vec<_>, _>>()?;
 
         let index = state
             .evaluation_stack
@@ -49,15 +62,113 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Contains {
             .try_into_value()?
             .to_bigint()
             .unwrap_or_default();
-        let data = match storage.load(index)?.leaf_values {
-            LeafVariant::Map { data, .. } => data,
+
+        let leaf = storage.load(index.clone())?;
+        let data = match &leaf.leaf_values {
+            LeafVariant::Map { data } => data,
             LeafVariant::Array(_array) => return Err(RuntimeError::InvalidStorageValue),
         };
-        let found = data.into_iter().any(|(map_key, _value)| map_key == input);
 
-        state
-            .evaluation_stack
-            .push(Cell::Value(Scalar::new_constant_bool(found)))?;
+        // The pushed result must be pinned to the authenticated leaf data
+        // rather than computed out of circuit: a malicious prover could
+        // otherwise push any boolean they like regardless of what `data`
+        // actually contains. For every stored `(key, value)` pair, constrain
+        // a boolean `entry_matches` to the AND of each limb's in-circuit
+        // equality against `input` (`comparison::equals`, not Rust's `==`),
+        // then OR every `entry_matches` together into `found`. This covers
+        // both directions at once: `found` can only be `1` when some entry's
+        // key truly equals `input` (membership), and is forced to `0` when
+        // none does (exclusion), since every entry in the authenticated leaf
+        // was actually checked rather than assumed absent.
+        let mut found = Scalar::new_constant_bool(false);
+        for (entry_index, (map_key, _map_value)) in data.iter().enumerate() {
+            if map_key.len() != input.len() {
+                // `zip` below would silently stop at the shorter side,
+                // leaving any extra limbs of a longer `map_key` unchecked --
+                // a stored key could then differ from `input` only in those
+                // extra limbs and still be judged a match.
+                return Err(RuntimeError::InvalidStorageValue);
+            }
+
+            let mut entry_matches = Scalar::new_constant_bool(true);
+            for (limb_index, (key_limb, input_limb)) in map_key.iter().zip(input.iter()).enumerate()
+            {
+                let limb_matches = comparison::equals(
+                    cs.namespace(|| format!("entry {} limb {} equals input", entry_index, limb_index)),
+                    key_limb,
+                    input_limb,
+                )?;
+                entry_matches = and::and(
+                    cs.namespace(|| format!("entry {} limb {} and", entry_index, limb_index)),
+                    &entry_matches,
+                    &limb_matches,
+                )?;
+            }
+            found = or::or(
+                cs.namespace(|| format!("found or entry {}", entry_index)),
+                &found,
+                &entry_matches,
+            )?;
+        }
+
+        // The membership/exclusion result above is only meaningful if `data`
+        // itself is the data actually committed to storage: recompute the
+        // root from the already-loaded leaf's hash and authentication path
+        // with the same gadget `Leaf::new` uses out of circuit, and
+        // constrain it to equal the root the storage was opened against.
+        // `leaf_value_hash`/`authentication_path` vary per storage slot, so
+        // they must be allocated as witnesses (like `schnorr.rs`'s
+        // `Boolean::from(x_matches)`), not as `Boolean::constant`, which is
+        // reserved for values fixed across every circuit execution.
+        let leaf_hash_bits = leaf
+            .leaf_value_hash
+            .iter()
+            .enumerate()
+            .map(|(i, bit)| {
+                AllocatedBit::alloc(cs.namespace(|| format!("leaf hash bit {}", i)), Some(*bit))
+                    .map(Boolean::from)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RuntimeError::SynthesisError)?;
+        let path_bits = leaf
+            .authentication_path
+            .iter()
+            .enumerate()
+            .map(|(depth, sibling)| {
+                sibling
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| {
+                        AllocatedBit::alloc(
+                            cs.namespace(|| format!("path bit {} {}", depth, i)),
+                            Some(*bit),
+                        )
+                        .map(Boolean::from)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<Vec<_>>, _>>()
+            .map_err(RuntimeError::SynthesisError)?;
+
+        let recomputed_root = merkle_tree::restore_root(
+            cs.namespace(|| "restore root"),
+            &leaf_hash_bits,
+            &path_bits,
+            &index,
+        )?;
+
+        Boolean::enforce_equal(
+            cs.namespace(|| "root matches storage commitment"),
+            &recomputed_root,
+            &storage.root_hash(),
+        )
+        .map_err(RuntimeError::SynthesisError)?;
+
+        // `found` is the in-circuit OR over every authenticated entry's
+        // equality check above, so it is fully determined by the leaf the
+        // Merkle proof just bound to `storage.root_hash()` rather than by
+        // the prover's say-so.
+        state.evaluation_stack.push(Cell::Value(found))?;
 
         Ok(())
     }
@@ -0,0 +1,5 @@
+//!
+//! `std::collections::MTreeMap` native functions.
+//!
+
+pub mod contains;
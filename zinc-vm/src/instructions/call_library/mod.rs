@@ -0,0 +1,35 @@
+//!
+//! Contract standard library call dispatch.
+//!
+
+pub mod collections_mtreemap;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::IEngine;
+
+///
+/// A native (non-bytecode) contract standard library function, called with
+/// its arguments already sitting on top of `state.evaluation_stack` and the
+/// contract's storage available for Merkle-authenticated reads/writes.
+///
+/// Distinct from `call_std::INativeCallable`: these functions need
+/// `storage` and the caller's `ExecutionState` (for e.g. `tick`), whereas
+/// `call_std` functions are pure over the evaluation stack.
+///
+pub trait INativeCallable<E: IEngine, S: IMerkleTree<E>> {
+    ///
+    /// Pops this function's arguments off `state.evaluation_stack`, runs the
+    /// gadget (reading/writing `storage` as needed), and pushes its
+    /// result(s) back.
+    ///
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        cs: CS,
+        state: &mut ExecutionState<E>,
+        storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError>;
+}
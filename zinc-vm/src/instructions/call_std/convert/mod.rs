@@ -0,0 +1,5 @@
+//!
+//! `std::convert` native functions.
+//!
+
+pub mod from_bits_signed;
@@ -0,0 +1,44 @@
+//!
+//! The `std::crypto::blake2s` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::evaluation_stack::EvaluationStack;
+use crate::error::RuntimeError;
+use crate::gadgets::stdlib::crypto::blake2s::blake2s_gadget;
+use crate::instructions::call_std::INativeCallable;
+use crate::IEngine;
+
+pub struct Blake2s {
+    preimage_length: usize,
+}
+
+impl Blake2s {
+    pub fn new(preimage_length: usize) -> Self {
+        Self { preimage_length }
+    }
+}
+
+impl<E: IEngine> INativeCallable<E> for Blake2s {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result<(), RuntimeError> {
+        let preimage = stack
+            .pop_n(self.preimage_length)?
+            .iter()
+            .cloned()
+            .map(|cell| cell.try_into_value())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let digest = blake2s_gadget(cs.namespace(|| "blake2s"), &preimage)?;
+
+        for bit in digest {
+            stack.push(bit.into())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//!
+//! The `std::crypto::keccak256` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::evaluation_stack::EvaluationStack;
+use crate::error::RuntimeError;
+use crate::gadgets::stdlib::crypto::keccak256::keccak256;
+use crate::instructions::call_std::INativeCallable;
+use crate::IEngine;
+
+pub struct Keccak256 {
+    preimage_length: usize,
+}
+
+impl Keccak256 {
+    pub fn new(preimage_length: usize) -> Self {
+        Self { preimage_length }
+    }
+}
+
+impl<E: IEngine> INativeCallable<E> for Keccak256 {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result<(), RuntimeError> {
+        let preimage = stack
+            .pop_n(self.preimage_length)?
+            .iter()
+            .cloned()
+            .map(|cell| cell.try_into_value())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let digest = keccak256(cs.namespace(|| "keccak256"), &preimage)?;
+
+        for bit in digest {
+            stack.push(bit.into())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+//!
+//! `std::crypto` native functions.
+//!
+
+pub mod blake2s;
+pub mod keccak256;
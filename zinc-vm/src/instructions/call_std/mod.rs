@@ -0,0 +1,69 @@
+//!
+//! Standard library native function call dispatch.
+//!
+
+pub mod convert;
+pub mod crypto;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use zinc_bytecode::FunctionIdentifier;
+
+use crate::core::execution_state::evaluation_stack::EvaluationStack;
+use crate::error::RuntimeError;
+use crate::instructions::call_std::crypto::blake2s::Blake2s;
+use crate::instructions::call_std::crypto::keccak256::Keccak256;
+use crate::IEngine;
+
+///
+/// A native (non-bytecode) standard library function, called with the
+/// arguments it needs already sitting on top of `stack`.
+///
+pub trait INativeCallable<E: IEngine> {
+    ///
+    /// Pops this function's arguments off `stack`, runs the gadget, and
+    /// pushes its result(s) back.
+    ///
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result<(), RuntimeError>;
+}
+
+///
+/// Dispatches a `CallStd` instruction (`zinc_bytecode::instructions::CallStd`)
+/// to the gadget its `identifier` names: `FunctionIdentifier::CryptoKeccak256`
+/// and `CryptoBlake2s` now reach the real `Keccak256`/`Blake2s` gadgets
+/// through this match, not just this crate's own tests.
+///
+/// This closes the dispatch-arm gap, not the whole reachability chain: the
+/// executor this crate implements `IExecutable` against reads
+/// `zinc_build::Instruction`, a crate absent from this checkout, and no
+/// `zinc_build::Instruction::CallStd` variant (or dispatch-loop call site
+/// that would match it to this function) exists here either. So a compiled
+/// Zinc program still can't reach this dispatch end-to-end in this
+/// checkout; what's fixed is that the dispatch itself is a real function
+/// with real match arms instead of absent wiring.
+///
+pub fn call_std<E, CS>(
+    identifier: FunctionIdentifier,
+    cs: CS,
+    stack: &mut EvaluationStack<E>,
+    argument_count: usize,
+) -> Result<(), RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    match identifier {
+        FunctionIdentifier::CryptoKeccak256 => Keccak256::new(argument_count).call(cs, stack),
+        FunctionIdentifier::CryptoBlake2s => Blake2s::new(argument_count).call(cs, stack),
+        FunctionIdentifier::CryptoSha256 => {
+            Err(crate::error::MalformedBytecode::InvalidArguments(
+                "std::crypto::sha256 has no call_std gadget in this build".into(),
+            )
+            .into())
+        }
+    }
+}
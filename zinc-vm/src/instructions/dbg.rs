@@ -19,13 +19,26 @@ use crate::error::RuntimeError;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::IExecutable;
 
+/// A debug argument, kept in both its structured `BuildValue` form (for the
+/// default/`{:?}` JSON rendering) and its flattened scalars (for conversion
+/// specs like `{:x}`/`{:b}`, which only make sense for a single integer).
+struct Argument {
+    /// The argument's name at the `dbg!` call site, if the compiler captured
+    /// one (e.g. `dbg!("{x}", x)`), so `{name}` placeholders can resolve
+    /// against it the same way `{0}`/`{}` resolve against position.
+    name: Option<String>,
+    value: BuildValue,
+    flat: Vec<BigInt>,
+}
+
 impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
     fn execute(self, vm: &mut VM) -> Result<(), RuntimeError> {
-        let mut values = Vec::with_capacity(self.argument_types.len());
+        let mut arguments = Vec::with_capacity(self.argument_types.len());
+        let mut argument_names = self.argument_names.into_iter().rev();
 
         for argument_type in self.argument_types.into_iter().rev() {
+            let name = argument_names.next().flatten();
             let size = argument_type.size();
-            let mut flat = Vec::with_capacity(size);
 
             match argument_type {
                 BuildType::Contract(fields) => {
@@ -47,12 +60,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
                             .collect();
                         flat.extend(values);
                     }
-                    values.push(BuildValue::from_flat_values(
-                        BuildType::Contract(fields),
-                        flat.as_slice(),
-                    ));
+                    let value =
+                        BuildValue::from_flat_values(BuildType::Contract(fields), flat.as_slice());
+                    arguments.push(Argument { name, value, flat });
                 }
                 r#type => {
+                    let mut flat = Vec::with_capacity(size);
                     for _ in 0..size {
                         let value = vm.pop()?.try_into_value()?.to_bigint().ok_or_else(|| {
                             RuntimeError::SynthesisError(SynthesisError::AssignmentMissing)
@@ -60,19 +73,16 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
                         flat.push(value);
                     }
                     flat.reverse();
-                    values.push(BuildValue::from_flat_values(r#type, flat.as_slice()));
+                    let value = BuildValue::from_flat_values(r#type, flat.as_slice());
+                    arguments.push(Argument { name, value, flat });
                 }
             }
         }
+        arguments.reverse();
 
         if let Some(condition) = vm.condition_top()?.to_bigint() {
             if condition.is_positive() {
-                let mut buffer = self.format;
-                for value in values.into_iter().rev() {
-                    let json = serde_json::to_string(&value.into_json()).unwrap_or_default();
-                    buffer = buffer.replacen("{}", &json, 1);
-                }
-                eprintln!("{}", buffer);
+                eprintln!("{}", render(&self.format, &arguments)?);
             }
         }
 
@@ -80,6 +90,106 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
     }
 }
 
+///
+/// Fills in `format`'s `{0}`, `{1}`, `{}` (implicitly numbered), `{name}`
+/// and `{:x}`/`{:b}`/`{:?}` placeholders against `arguments`, in argument
+/// order. Unlike the plain `replacen("{}", ...)` this replaces, a
+/// placeholder may reference any argument in any order, and reuse one more
+/// than once. `{name}` resolves against `Argument::name`, the argument's
+/// name at the `dbg!` call site as captured by `Dbg::argument_names`.
+///
+fn render(format: &str, arguments: &[Argument]) -> Result<String, RuntimeError> {
+    let mut output = String::with_capacity(format.len());
+    let mut implicit_index = 0;
+    let mut rest = format;
+
+    loop {
+        match rest.find('{') {
+            None => {
+                output.push_str(rest);
+                break;
+            }
+            Some(start) => {
+                output.push_str(&rest[..start]);
+                let after_brace = &rest[start + 1..];
+                let end = after_brace.find('}').ok_or_else(|| {
+                    RuntimeError::InvalidDebugFormat(format!(
+                        "unterminated placeholder in debug format string {:?}",
+                        format
+                    ))
+                })?;
+                let placeholder = &after_brace[..end];
+                rest = &after_brace[end + 1..];
+
+                let (reference, spec) = match placeholder.split_once(':') {
+                    Some((reference, spec)) => (reference, spec),
+                    None => (placeholder, ""),
+                };
+
+                let index = if reference.is_empty() {
+                    let index = implicit_index;
+                    implicit_index += 1;
+                    index
+                } else if let Ok(index) = reference.parse::<usize>() {
+                    index
+                } else {
+                    arguments
+                        .iter()
+                        .position(|argument| argument.name.as_deref() == Some(reference))
+                        .ok_or_else(|| {
+                            RuntimeError::InvalidDebugFormat(format!(
+                                "named debug placeholder `{{{}}}` does not match any \
+                                 argument name captured for this `dbg!` call",
+                                reference
+                            ))
+                        })?
+                };
+
+                let argument = arguments.get(index).ok_or_else(|| {
+                    RuntimeError::InvalidDebugFormat(format!(
+                        "debug placeholder `{{{}}}` references argument {}, but only {} \
+                         were passed",
+                        placeholder,
+                        index,
+                        arguments.len()
+                    ))
+                })?;
+
+                output.push_str(&render_argument(argument, spec)?);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Renders a single resolved argument according to its conversion spec:
+/// `""`/`"?"` for the default JSON form, `"x"`/`"b"` to print a
+/// single-scalar argument as hex/binary.
+fn render_argument(argument: &Argument, spec: &str) -> Result<String, RuntimeError> {
+    match spec {
+        "" | "?" => {
+            Ok(serde_json::to_string(&argument.value.clone().into_json()).unwrap_or_default())
+        }
+        "x" | "b" => match argument.flat.as_slice() {
+            [scalar] => Ok(match spec {
+                "x" => format!("{:x}", scalar),
+                "b" => format!("{:b}", scalar),
+                _ => unreachable!(),
+            }),
+            _ => Err(RuntimeError::InvalidDebugFormat(format!(
+                "debug conversion spec `{{:{}}}` requires a single-scalar argument, found {} scalars",
+                spec,
+                argument.flat.len(),
+            ))),
+        },
+        other => Err(RuntimeError::InvalidDebugFormat(format!(
+            "unsupported debug conversion spec `{{:{}}}`",
+            other
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use num::BigInt;
@@ -90,7 +200,20 @@ mod tests {
     fn test() {
         TestRunner::new()
             .push(zinc_build::Push::new_field(BigInt::from(42)))
-            .push(zinc_build::Dbg::new("Value: {}".into(), vec![]))
+            .push(zinc_build::Dbg::new("Value: {}".into(), vec![], vec![]))
+            .test::<u32>(&[])
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    #[test]
+    fn named_placeholder_resolves_against_argument_name() {
+        TestRunner::new()
+            .push(zinc_build::Push::new_field(BigInt::from(42)))
+            .push(zinc_build::Dbg::new(
+                "Value: {value}".into(),
+                vec![],
+                vec![Some("value".into())],
+            ))
             .test::<u32>(&[])
             .expect(zinc_const::panic::TEST_DATA_VALID);
     }
@@ -1,3 +1,5 @@
+mod hash;
+
 use std::fmt::{Debug, Display, Error, Formatter};
 use std::marker::PhantomData;
 
@@ -127,6 +129,210 @@ where
         &mut self.cs
     }
 
+    ///
+    /// Computes `left + right` modulo `2^bit_width`, following the uint32 "addmany"
+    /// technique: the operands are summed as field elements (the field modulus is far
+    /// wider than any `bit_width` Zinc's integer types use, so this cannot overflow the
+    /// field itself), the raw sum is decomposed into `bit_width + 1` little-endian bits
+    /// via `into_bits_le_fixed` (one extra bit is always enough carry room for the sum of
+    /// two `bit_width`-bit values), and only the low `bit_width` bits are repacked as the
+    /// wrapped result — the high carry bit is allocated and constrained by the
+    /// decomposition, then simply discarded. This gives Zinc's unsigned integer types
+    /// defined overflow semantics instead of relying solely on the field and a later
+    /// `type_check` pass.
+    ///
+    pub fn add_wrapping(
+        &mut self,
+        left: FrPrimitive<E>,
+        right: FrPrimitive<E>,
+        bit_width: usize,
+    ) -> Result<FrPrimitive<E>, RuntimeError> {
+        if bit_width as u32 > E::Fr::CAPACITY {
+            return Err(RuntimeError::InternalError(format!(
+                "add_wrapping bit_width {} exceeds field capacity {}",
+                bit_width,
+                E::Fr::CAPACITY,
+            )));
+        }
+
+        let sum = self.add(left, right)?;
+
+        let carry_bits = bit_width + 1;
+        let bits: Vec<FrPrimitive<E>> = {
+            let mut cs = self.cs_namespace();
+            let num = sum.as_allocated_num(cs.namespace(|| "as_allocated_num"))?;
+            let raw_bits = num
+                .into_bits_le_fixed(cs.namespace(|| "into_bits_le_fixed"), carry_bits)
+                .map_err(RuntimeError::SynthesisError)?;
+
+            raw_bits
+                .into_iter()
+                .map(|bit| {
+                    FrPrimitive::new(
+                        bit.get_value_field::<E>(),
+                        bit.get_variable().expect("bit value expected").get_variable(),
+                    )
+                })
+                .collect()
+        };
+
+        let mut wrapped = self.constant_bigint(&0.into())?;
+        for (index, bit) in bits.into_iter().take(bit_width).enumerate() {
+            let weight = self.constant_bigint(&(1u64 << index).into())?;
+            let term = self.mul(weight, bit)?;
+            wrapped = self.add(wrapped, term)?;
+        }
+
+        wrapped.data_type = Some(DataType {
+            signed: false,
+            length: bit_width,
+        });
+
+        Ok(wrapped)
+    }
+
+    /// Decomposes `value` into `bit_width` little-endian boolean bits.
+    fn decompose(
+        &mut self,
+        value: FrPrimitive<E>,
+        bit_width: usize,
+    ) -> Result<Vec<FrPrimitive<E>>, RuntimeError> {
+        let mut cs = self.cs_namespace();
+        let num = value.as_allocated_num(cs.namespace(|| "as_allocated_num"))?;
+        let bits = num
+            .into_bits_le_fixed(cs.namespace(|| "into_bits_le_fixed"), bit_width)
+            .map_err(RuntimeError::SynthesisError)?;
+
+        Ok(bits
+            .into_iter()
+            .map(|bit| {
+                FrPrimitive::new(
+                    bit.get_value_field::<E>(),
+                    bit.get_variable().expect("bit value expected").get_variable(),
+                )
+            })
+            .collect())
+    }
+
+    /// Repacks little-endian boolean `bits` into a single field element via a weighted sum.
+    /// The bits produced by `decompose` and by the boolean `and`/`or`/`xor` gadgets are plain
+    /// already-constrained `FrPrimitive`s rather than `franklin_crypto`'s own `Boolean`
+    /// wrapper, so this packs them the same way `add_wrapping`'s carry truncation does,
+    /// instead of going through `AllocatedNum::pack_bits_to_element`.
+    fn repack(&mut self, bits: &[FrPrimitive<E>]) -> Result<FrPrimitive<E>, RuntimeError> {
+        let mut accumulator = self.constant_bigint(&0.into())?;
+        for (index, bit) in bits.iter().enumerate() {
+            let weight = self.constant_bigint(&(BigInt::from(1) << index))?;
+            let term = self.mul(weight, bit.clone())?;
+            accumulator = self.add(accumulator, term)?;
+        }
+        Ok(accumulator)
+    }
+
+    /// Applies the single-bit gadget `op` to `left` and `right` position-by-position over
+    /// `bit_width` bits, repacking the result into a field element.
+    fn bitwise<F>(
+        &mut self,
+        left: FrPrimitive<E>,
+        right: FrPrimitive<E>,
+        bit_width: usize,
+        op: F,
+    ) -> Result<FrPrimitive<E>, RuntimeError>
+    where
+        F: Fn(&mut Self, FrPrimitive<E>, FrPrimitive<E>) -> Result<FrPrimitive<E>, RuntimeError>,
+    {
+        let left_bits = self.decompose(left, bit_width)?;
+        let right_bits = self.decompose(right, bit_width)?;
+
+        let mut result_bits = Vec::with_capacity(bit_width);
+        for (left_bit, right_bit) in left_bits.into_iter().zip(right_bits.into_iter()) {
+            result_bits.push(op(self, left_bit, right_bit)?);
+        }
+
+        self.repack(&result_bits)
+    }
+
+    ///
+    /// Bitwise `left & right` over `bit_width` bits.
+    ///
+    pub fn bit_and(
+        &mut self,
+        left: FrPrimitive<E>,
+        right: FrPrimitive<E>,
+        bit_width: usize,
+    ) -> Result<FrPrimitive<E>, RuntimeError> {
+        self.bitwise(left, right, bit_width, Self::and)
+    }
+
+    ///
+    /// Bitwise `left | right` over `bit_width` bits.
+    ///
+    pub fn bit_or(
+        &mut self,
+        left: FrPrimitive<E>,
+        right: FrPrimitive<E>,
+        bit_width: usize,
+    ) -> Result<FrPrimitive<E>, RuntimeError> {
+        self.bitwise(left, right, bit_width, Self::or)
+    }
+
+    ///
+    /// Bitwise `left ^ right` over `bit_width` bits.
+    ///
+    pub fn bit_xor(
+        &mut self,
+        left: FrPrimitive<E>,
+        right: FrPrimitive<E>,
+        bit_width: usize,
+    ) -> Result<FrPrimitive<E>, RuntimeError> {
+        self.bitwise(left, right, bit_width, Self::xor)
+    }
+
+    ///
+    /// Shifts `value` left by `amount` bits within `bit_width`, dropping the top `amount`
+    /// bits and zero-filling the bottom.
+    ///
+    pub fn shl(
+        &mut self,
+        value: FrPrimitive<E>,
+        amount: usize,
+        bit_width: usize,
+    ) -> Result<FrPrimitive<E>, RuntimeError> {
+        let bits = self.decompose(value, bit_width)?;
+        let zero = self.zero()?;
+
+        let shifted: Vec<FrPrimitive<E>> = (0..bit_width)
+            .map(|index| {
+                if index >= amount {
+                    bits[index - amount].clone()
+                } else {
+                    zero.clone()
+                }
+            })
+            .collect();
+
+        self.repack(&shifted)
+    }
+
+    ///
+    /// Shifts `value` right by `amount` bits within `bit_width`, zero-filling from the top.
+    ///
+    pub fn shr(
+        &mut self,
+        value: FrPrimitive<E>,
+        amount: usize,
+        bit_width: usize,
+    ) -> Result<FrPrimitive<E>, RuntimeError> {
+        let bits = self.decompose(value, bit_width)?;
+        let zero = self.zero()?;
+
+        let shifted: Vec<FrPrimitive<E>> = (0..bit_width)
+            .map(|index| bits.get(index + amount).cloned().unwrap_or_else(|| zero.clone()))
+            .collect();
+
+        self.repack(&shifted)
+    }
+
     fn abs(&mut self, value: FrPrimitive<E>) -> Result<FrPrimitive<E>, RuntimeError> {
         let zero = self.zero()?;
         let neg = PrimitiveOperations::neg(self, value.clone())?;
@@ -750,6 +956,29 @@ where
         self.recursive_select(array, bits.as_slice())
     }
 
+    ///
+    /// NOT BATCHED, by design: an earlier attempt packed every slot's
+    /// `curr_index == i` binding into one shared linear equation
+    /// (`Σ value_i·2^offset_i == Σ weight_i·variable_i`) to replace `array.len()`
+    /// `constant_bigint` calls with roughly one. That was unsound — nothing
+    /// range-constrained each `variable_i` to its `bit_width` slot, so a
+    /// prover could satisfy the packed sum via carry overlap between slots
+    /// while individual `variable_i` held the wrong index — and got
+    /// reverted.
+    ///
+    /// A sound version needs each packed operand individually proven to fit
+    /// in `bit_width` bits before packing (the fix a plain revert doesn't
+    /// give you), but that per-operand range check is itself a bit-by-bit
+    /// decomposition costing `bit_width` boolean constraints — at least as
+    /// many as the single `constant_bigint` equality check it would replace
+    /// for any `array.len() > 2` (`bit_width = tree_height(array.len()) >
+    /// 1`), and strictly more once the shared packed equation is added on
+    /// top. There is no field-element range check cheaper than
+    /// O(bit_width) without additional machinery (e.g. lookup arguments)
+    /// this circuit system doesn't have, so packing these bindings can't
+    /// reduce the constraint count here — only relocate it. The per-slot
+    /// `constant_bigint` below is kept as the actual, sound implementation.
+    ///
     fn array_set(
         &mut self,
         array: &[FrPrimitive<E>],
@@ -0,0 +1,250 @@
+//!
+//! Generates the instruction set from `instructions.in`.
+//!
+//! This replaces what used to be three hand-maintained, easy-to-desync
+//! pieces -- the `InstructionCode` enum, the per-instruction `Instruction`
+//! impls, and the `decode_instruction` dispatch -- with a single table the
+//! generator reads once and expands consistently.
+//!
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+struct Field {
+    name: String,
+    ty: String,
+}
+
+struct Instruction {
+    name: String,
+    opcode: u8,
+    inputs: i64,
+    outputs: i64,
+    fields: Vec<Field>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=instructions.in");
+
+    let table = fs::read_to_string("instructions.in").expect("reading instructions.in");
+    let instructions = parse_table(&table);
+
+    let mut seen_opcodes = HashSet::new();
+    for instruction in &instructions {
+        if !seen_opcodes.insert(instruction.opcode) {
+            panic!(
+                "duplicate opcode 0x{:02x} for instruction `{}`",
+                instruction.opcode, instruction.name
+            );
+        }
+    }
+
+    let generated = render(&instructions);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let destination = Path::new(&out_dir).join("instructions.rs");
+    fs::write(destination, generated).expect("writing generated instructions.rs");
+}
+
+fn parse_table(source: &str) -> Vec<Instruction> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+
+            let name = parts.next().expect("instruction name").to_owned();
+            let opcode = parts.next().expect("instruction opcode");
+            let opcode = u8::from_str_radix(opcode.trim_start_matches("0x"), 16)
+                .unwrap_or_else(|_| panic!("invalid opcode for `{}`", name));
+            let inputs = parts
+                .next()
+                .expect("instruction inputs")
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid inputs count for `{}`", name));
+            let outputs = parts
+                .next()
+                .expect("instruction outputs")
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid outputs count for `{}`", name));
+
+            let fields = parts
+                .map(|field| {
+                    let mut split = field.splitn(2, ':');
+                    let name = split.next().expect("field name").to_owned();
+                    let ty = split.next().expect("field type").to_owned();
+                    Field { name, ty }
+                })
+                .collect();
+
+            Instruction {
+                name,
+                opcode,
+                inputs,
+                outputs,
+                fields,
+            }
+        })
+        .collect()
+}
+
+fn rust_type(ty: &str) -> &'static str {
+    match ty {
+        "u8" => "u8",
+        "usize" => "usize",
+        "bool" => "bool",
+        "String" => "String",
+        "OptionString" => "Option<String>",
+        "BigInt" => "num_bigint::BigInt",
+        other => panic!("unsupported field type `{}`", other),
+    }
+}
+
+fn write_call(ty: &str, expr: &str) -> String {
+    match ty {
+        "u8" => format!("vec![{}]", expr),
+        "usize" => format!("crate::vlq::write_unsigned({})", expr),
+        "bool" => format!("crate::vlq::write_bool({})", expr),
+        "String" => format!("crate::vlq::write_string(&{})", expr),
+        "OptionString" => format!("crate::vlq::write_option_string(&{})", expr),
+        "BigInt" => format!("crate::vlq::write_bigint(&{})", expr),
+        other => panic!("unsupported field type `{}`", other),
+    }
+}
+
+fn read_call(ty: &str, expr: &str) -> String {
+    match ty {
+        "u8" => format!(
+            "{}.first().copied().map(|b| (b, 1)).ok_or(crate::DecodingError::UnexpectedEOF)",
+            expr
+        ),
+        "usize" => format!("crate::vlq::read_unsigned({})", expr),
+        "bool" => format!("crate::vlq::read_bool({})", expr),
+        "String" => format!("crate::vlq::read_string({})", expr),
+        "OptionString" => format!("crate::vlq::read_option_string({})", expr),
+        "BigInt" => format!("crate::vlq::read_bigint({})", expr),
+        other => panic!("unsupported field type `{}`", other),
+    }
+}
+
+fn render(instructions: &[Instruction]) -> String {
+    let mut out = String::new();
+
+    out.push_str("// This file is generated from `instructions.in` by `build.rs`. Do not edit.\n\n");
+
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\npub enum InstructionCode {\n");
+    for instruction in instructions {
+        out.push_str(&format!(
+            "    {} = 0x{:02x},\n",
+            instruction.name, instruction.opcode
+        ));
+    }
+    out.push_str("}\n\n");
+
+    for instruction in instructions {
+        out.push_str(&format!("#[derive(Debug, Clone, PartialEq)]\npub struct {} {{\n", instruction.name));
+        for field in &instruction.fields {
+            out.push_str(&format!("    pub {}: {},\n", field.name, rust_type(&field.ty)));
+        }
+        out.push_str("}\n\n");
+
+        let args = instruction
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name, rust_type(&field.ty)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let inits = instruction
+            .fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        out.push_str(&format!(
+            "impl {name} {{\n    pub fn new({args}) -> Self {{\n        Self {{ {inits} }}\n    }}\n\n",
+            name = instruction.name,
+            args = args,
+            inits = inits,
+        ));
+
+        out.push_str("    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), crate::DecodingError> {\n");
+        out.push_str("        let mut offset = 1;\n");
+        for field in &instruction.fields {
+            out.push_str(&format!(
+                "        let ({field}, {field}_len) = {call}?;\n        offset += {field}_len;\n",
+                field = field.name,
+                call = read_call(&field.ty, "&bytes[offset..]"),
+            ));
+        }
+        out.push_str(&format!(
+            "        Ok((Self {{ {inits} }}, offset))\n    }}\n}}\n\n",
+            inits = inits,
+        ));
+
+        out.push_str(&format!("impl crate::Instruction for {} {{\n", instruction.name));
+
+        out.push_str("    fn to_assembly(&self) -> String {\n");
+        if instruction.fields.is_empty() {
+            out.push_str(&format!("        \"{}\".to_lowercase()\n    }}\n\n", instruction.name));
+        } else {
+            let placeholders = instruction.fields.iter().map(|_| "{:?}").collect::<Vec<_>>().join(" ");
+            let refs = instruction
+                .fields
+                .iter()
+                .map(|field| format!("self.{}", field.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!(
+                "        format!(\"{} {}\", {})\n    }}\n\n",
+                instruction.name.to_lowercase(),
+                placeholders,
+                refs
+            ));
+        }
+
+        out.push_str(&format!(
+            "    fn code(&self) -> crate::InstructionCode {{\n        crate::InstructionCode::{}\n    }}\n\n",
+            instruction.name
+        ));
+
+        out.push_str("    fn encode(&self) -> Vec<u8> {\n");
+        out.push_str(&format!(
+            "        let mut bytes = vec![crate::InstructionCode::{} as u8];\n",
+            instruction.name
+        ));
+        for field in &instruction.fields {
+            out.push_str(&format!(
+                "        bytes.extend({});\n",
+                write_call(&field.ty, &format!("self.{}", field.name))
+            ));
+        }
+        out.push_str("        bytes\n    }\n\n");
+
+        out.push_str(&format!(
+            "    fn inputs_count(&self) -> usize {{\n        {}\n    }}\n\n",
+            instruction.inputs.max(0)
+        ));
+        out.push_str(&format!(
+            "    fn outputs_count(&self) -> usize {{\n        {}\n    }}\n\n",
+            instruction.outputs.max(0)
+        ));
+        out.push_str("    fn as_any(&self) -> &dyn std::any::Any {\n        self\n    }\n}\n\n");
+    }
+
+    out.push_str("pub(crate) fn decode_instruction(\n    bytes: &[u8],\n) -> Result<(Box<dyn crate::Instruction>, usize), crate::DecodingError> {\n");
+    out.push_str("    if bytes.is_empty() {\n        return Err(crate::DecodingError::UnexpectedEOF);\n    }\n\n");
+    out.push_str("    match bytes[0] {\n");
+    for instruction in instructions {
+        out.push_str(&format!(
+            "        x if x == InstructionCode::{name} as u8 => {name}::decode(bytes)\n            .map(|(s, len)| -> (Box<dyn crate::Instruction>, usize) {{ (Box::new(s), len) }}),\n",
+            name = instruction.name,
+        ));
+    }
+    out.push_str("        code => Err(crate::DecodingError::UnknownInstructionCode(code)),\n    }\n}\n");
+
+    out
+}
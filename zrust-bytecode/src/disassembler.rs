@@ -0,0 +1,160 @@
+//!
+//! The bytecode disassembler.
+//!
+//! Turns a decoded instruction stream back into annotated, human-readable
+//! assembly: each line is prefixed with its byte offset, and control-flow
+//! targets are printed as resolved synthetic labels instead of raw addresses.
+//!
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::decode_all_instructions;
+use crate::Call;
+use crate::DecodingError;
+use crate::Instruction;
+use crate::InstructionCode;
+
+///
+/// One decoded instruction together with the byte offset it was read from.
+///
+struct Entry {
+    offset: usize,
+    instruction: Box<dyn Instruction>,
+}
+
+///
+/// A synthetic label resolved for some byte offset.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Label {
+    Function(usize),
+    LoopBegin(usize),
+    LoopEnd(usize),
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Function(index) => write!(f, "func_{}", index),
+            Self::LoopBegin(index) => write!(f, "loop_{}_begin", index),
+            Self::LoopEnd(index) => write!(f, "loop_{}_end", index),
+        }
+    }
+}
+
+///
+/// Decodes `bytes` and renders the annotated assembly listing.
+///
+pub fn disassemble(bytes: &[u8]) -> Result<String, DecodingError> {
+    let entries = decode_with_offsets(bytes)?;
+    let labels = resolve_labels(&entries);
+    Ok(render(&entries, &labels))
+}
+
+fn decode_with_offsets(bytes: &[u8]) -> Result<Vec<Entry>, DecodingError> {
+    let instructions = decode_all_instructions(bytes)?;
+
+    let mut entries = Vec::with_capacity(instructions.len());
+    let mut offset = 0;
+    for instruction in instructions {
+        let entry = Entry {
+            offset,
+            instruction,
+        };
+        offset += entry.instruction.encode().len();
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+///
+/// Builds the offset -> label map: `Call` targets become function entry
+/// labels, and each `LoopBegin`/`LoopEnd` pair is numbered in nesting order.
+///
+fn resolve_labels(entries: &[Entry]) -> BTreeMap<usize, Vec<Label>> {
+    let mut labels: BTreeMap<usize, Vec<Label>> = BTreeMap::new();
+    let mut loop_counter = 0;
+    let mut loop_stack = Vec::new();
+
+    for entry in entries {
+        match entry.instruction.code() {
+            InstructionCode::Call => {
+                if let Some(address) = call_target(entry.instruction.as_ref()) {
+                    labels
+                        .entry(address)
+                        .or_default()
+                        .push(Label::Function(address));
+                }
+            }
+            InstructionCode::LoopBegin => {
+                let index = loop_counter;
+                loop_counter += 1;
+                loop_stack.push(index);
+                labels
+                    .entry(entry.offset)
+                    .or_default()
+                    .push(Label::LoopBegin(index));
+            }
+            InstructionCode::LoopEnd => {
+                let index = loop_stack.pop().unwrap_or(loop_counter);
+                labels
+                    .entry(entry.offset)
+                    .or_default()
+                    .push(Label::LoopEnd(index));
+            }
+            _ => {}
+        }
+    }
+
+    labels
+}
+
+///
+/// Extracts the jump target address from a `Call` instruction via its typed
+/// `address` field.
+///
+fn call_target(instruction: &dyn Instruction) -> Option<usize> {
+    instruction.as_any().downcast_ref::<Call>().map(|call| call.address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::call_target;
+    use crate::Call;
+    use crate::Instruction;
+
+    #[test]
+    fn call_target_reads_the_typed_address_field() {
+        let call = Call::new(42, 3);
+
+        assert_eq!(call_target(&call as &dyn Instruction), Some(42));
+    }
+
+    #[test]
+    fn call_target_is_none_for_other_instructions() {
+        let pop = crate::Pop {};
+
+        assert_eq!(call_target(&pop as &dyn Instruction), None);
+    }
+}
+
+fn render(entries: &[Entry], labels: &BTreeMap<usize, Vec<Label>>) -> String {
+    let mut output = String::new();
+
+    for entry in entries {
+        if let Some(entry_labels) = labels.get(&entry.offset) {
+            for label in entry_labels {
+                output.push_str(&format!("{}:\n", label));
+            }
+        }
+
+        output.push_str(&format!(
+            "{:>6}: {}\n",
+            entry.offset,
+            entry.instruction.to_assembly()
+        ));
+    }
+
+    output
+}
@@ -0,0 +1,140 @@
+//!
+//! The variable-length quantity encoding used by the bytecode instruction operands.
+//!
+
+use num_bigint::BigInt;
+use num_bigint::Sign;
+
+use crate::DecodingError;
+
+///
+/// Encodes `value` as an unsigned LEB128-style variable-length quantity.
+///
+pub fn write_unsigned(mut value: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        bytes.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+    bytes
+}
+
+///
+/// Decodes an unsigned variable-length quantity, returning the value and the
+/// number of bytes consumed.
+///
+pub fn read_unsigned(bytes: &[u8]) -> Result<(usize, usize), DecodingError> {
+    let mut value: usize = 0;
+    let mut shift = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        value |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, index + 1));
+        }
+        shift += 7;
+    }
+    Err(DecodingError::UnexpectedEOF)
+}
+
+///
+/// Encodes a boolean as a single byte.
+///
+pub fn write_bool(value: bool) -> Vec<u8> {
+    vec![value as u8]
+}
+
+///
+/// Decodes a single-byte boolean.
+///
+pub fn read_bool(bytes: &[u8]) -> Result<(bool, usize), DecodingError> {
+    match bytes.first() {
+        Some(0) => Ok((false, 1)),
+        Some(_) => Ok((true, 1)),
+        None => Err(DecodingError::UnexpectedEOF),
+    }
+}
+
+///
+/// Encodes a UTF-8 string as a VLQ length prefix followed by its bytes.
+///
+pub fn write_string(value: &str) -> Vec<u8> {
+    let mut bytes = write_unsigned(value.len());
+    bytes.extend_from_slice(value.as_bytes());
+    bytes
+}
+
+///
+/// Decodes a VLQ-length-prefixed UTF-8 string.
+///
+pub fn read_string(bytes: &[u8]) -> Result<(String, usize), DecodingError> {
+    let (length, prefix_len) = read_unsigned(bytes)?;
+    let data = bytes
+        .get(prefix_len..prefix_len + length)
+        .ok_or(DecodingError::UnexpectedEOF)?;
+    let value = String::from_utf8(data.to_vec()).map_err(|_| DecodingError::ConstantTooLong)?;
+    Ok((value, prefix_len + length))
+}
+
+///
+/// Encodes `Option<String>` as a presence byte followed by the string if present.
+///
+pub fn write_option_string(value: &Option<String>) -> Vec<u8> {
+    match value {
+        None => vec![0],
+        Some(string) => {
+            let mut bytes = vec![1];
+            bytes.extend(write_string(string));
+            bytes
+        }
+    }
+}
+
+///
+/// Decodes an `Option<String>` written by [`write_option_string`].
+///
+pub fn read_option_string(bytes: &[u8]) -> Result<(Option<String>, usize), DecodingError> {
+    match bytes.first() {
+        Some(0) => Ok((None, 1)),
+        Some(_) => {
+            let (string, len) = read_string(&bytes[1..])?;
+            Ok((Some(string), len + 1))
+        }
+        None => Err(DecodingError::UnexpectedEOF),
+    }
+}
+
+///
+/// Encodes a `BigInt` as a sign byte, a VLQ length, and the big-endian magnitude bytes.
+///
+pub fn write_bigint(value: &BigInt) -> Vec<u8> {
+    let (sign, magnitude) = value.to_bytes_be();
+    let mut bytes = vec![(sign == Sign::Minus) as u8];
+    bytes.extend(write_unsigned(magnitude.len()));
+    bytes.extend(magnitude);
+    bytes
+}
+
+///
+/// Decodes a `BigInt` written by [`write_bigint`].
+///
+pub fn read_bigint(bytes: &[u8]) -> Result<(BigInt, usize), DecodingError> {
+    let is_negative = match bytes.first() {
+        Some(byte) => *byte != 0,
+        None => return Err(DecodingError::UnexpectedEOF),
+    };
+    let (length, length_len) = read_unsigned(&bytes[1..])?;
+    let offset = 1 + length_len;
+    let magnitude = bytes
+        .get(offset..offset + length)
+        .ok_or(DecodingError::UnexpectedEOF)?;
+    let sign = if is_negative { Sign::Minus } else { Sign::Plus };
+    let value = BigInt::from_bytes_be(sign, magnitude);
+    Ok((value, offset + length))
+}